@@ -0,0 +1,57 @@
+/// The Azure OpenAI API version used when none is configured.
+pub const DEFAULT_AZURE_API_VERSION: &str = "2025-04-01-preview";
+
+/// Configuration for sending requests to [Azure OpenAI](https://learn.microsoft.com/en-us/azure/ai-services/openai/how-to/responses) instead of OpenAI.
+///
+/// When set on a [`ClientBuilder`](crate::ClientBuilder), the client authenticates with an `api-key` header,
+/// sends requests to `{endpoint}/openai/responses`, and appends the `api-version` query parameter to every request.
+///
+/// # Examples
+/// ```rust
+/// use openai_responses::{AzureConfig, Client};
+///
+/// let client = Client::builder()
+///     .api_key("my-azure-api-key")
+///     .azure(AzureConfig::new("https://my-resource.openai.azure.com").deployment("my-gpt-4o"))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AzureConfig {
+    pub(crate) endpoint: String,
+    pub(crate) api_version: String,
+    pub(crate) deployment: Option<String>,
+}
+
+impl AzureConfig {
+    /// Creates a new [`AzureConfig`] for the given resource endpoint, e.g. `https://my-resource.openai.azure.com`.
+    #[must_use]
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            deployment: None,
+            endpoint: endpoint.into(),
+            api_version: DEFAULT_AZURE_API_VERSION.to_string(),
+        }
+    }
+
+    /// Sets the `api-version` query parameter. Defaults to [`DEFAULT_AZURE_API_VERSION`].
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    /// Sets the deployment name to use for every request, replacing the request's `model`.
+    ///
+    /// If no deployment is set, the request's `model` is sent as-is and must match a deployment name.
+    #[must_use]
+    pub fn deployment(mut self, deployment: impl Into<String>) -> Self {
+        self.deployment = Some(deployment.into());
+        self
+    }
+
+    /// The base URL for the Responses API on this Azure resource.
+    pub(crate) fn base_url(&self) -> String {
+        format!("{}/openai", self.endpoint.trim_end_matches('/'))
+    }
+}
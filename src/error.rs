@@ -0,0 +1,158 @@
+use reqwest::{StatusCode, header::HeaderMap};
+use serde::Deserialize;
+
+use crate::{
+    metadata::{RateLimits, header_str},
+    schema::SchemaError,
+    types::Error,
+};
+
+/// Errors that can occur when calling the Responses API.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The API responded with an error.
+    #[error("{0}")]
+    Api(Box<ApiError>),
+    /// The request could not be sent, or the response could not be read.
+    #[error("Request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    /// A background response did not finish before the timeout elapsed.
+    #[error("Timed out waiting for response {response_id} to complete")]
+    Timeout {
+        /// The ID of the response that was being waited on.
+        response_id: String,
+    },
+    /// The request contains a strict schema that the API would reject. It was not sent.
+    #[error("Invalid request: {0}")]
+    InvalidSchema(#[from] SchemaError),
+    /// The model refused to produce the requested structured output.
+    #[error("The model refused to respond: {refusal}")]
+    Refusal {
+        /// The refusal message generated by the model.
+        refusal: String,
+    },
+    /// The output of the model could not be deserialized into the requested type.
+    #[error("Failed to parse model output: {error}")]
+    InvalidOutput {
+        /// The underlying deserialization error.
+        #[source]
+        error: serde_json::Error,
+        /// The raw text output of the model.
+        text: String,
+    },
+    /// The response body could not be deserialized.
+    #[error("Failed to parse response body: {error}")]
+    Parsing {
+        /// The underlying deserialization error.
+        #[source]
+        error: serde_json::Error,
+        /// The raw response body.
+        body: String,
+    },
+}
+
+impl ClientError {
+    /// The HTTP status code returned by the API, if the request reached it.
+    #[must_use]
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Api(error) => Some(error.status),
+            Self::Http(error) => error.status(),
+            Self::Parsing { .. }
+            | Self::Timeout { .. }
+            | Self::InvalidSchema(_)
+            | Self::Refusal { .. }
+            | Self::InvalidOutput { .. } => None,
+        }
+    }
+
+    #[cfg(feature = "stream")]
+    /// Whether trying the request again might succeed: the connection failed, or the API returned a status worth retrying.
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Self::Http(error) if error.status().is_none() => true,
+            error => error.status().is_some_and(|status| {
+                crate::RetryPolicy::should_retry_response(status, &HeaderMap::new())
+            }),
+        }
+    }
+
+    /// The OpenAI error object returned by the API, if any.
+    #[must_use]
+    pub fn api_error(&self) -> Option<&Error> {
+        match self {
+            Self::Api(error) => error.error.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<ApiError> for ClientError {
+    fn from(error: ApiError) -> Self {
+        Self::Api(Box::new(error))
+    }
+}
+
+/// An error response returned by the API.
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+    /// The OpenAI error object, if the body contained one.
+    pub error: Option<Error>,
+    /// The `x-request-id` header, useful when contacting support.
+    pub request_id: Option<String>,
+    /// The rate limit state reported by the response headers.
+    pub rate_limits: RateLimits,
+    /// The raw response body.
+    pub body: String,
+}
+
+impl ApiError {
+    pub(crate) fn new(status: StatusCode, headers: &HeaderMap, body: String) -> Self {
+        #[derive(Deserialize)]
+        struct ErrorBody {
+            error: Error,
+        }
+
+        Self {
+            status,
+            rate_limits: RateLimits::from_headers(headers),
+            request_id: header_str(headers, "x-request-id").map(ToString::to_string),
+            error: serde_json::from_str::<ErrorBody>(&body)
+                .ok()
+                .map(|body| body.error),
+            body,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "OpenAI API error ({}): {}", self.status, error.message)?,
+            None => write!(f, "OpenAI API error ({}): {}", self.status, self.body)?,
+        }
+
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request ID: {request_id})")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// The arguments of a function call could not be deserialized.
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to parse the arguments of the `{name}` function call: {source}")]
+pub struct ArgumentsError {
+    /// The name of the function that was called.
+    pub name: String,
+    /// The ID of the function call.
+    pub call_id: String,
+    /// The underlying deserialization error.
+    #[source]
+    pub source: serde_json::Error,
+}
@@ -1,29 +1,70 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
-#![allow(clippy::doc_markdown)]
+#![allow(clippy::doc_markdown, clippy::multiple_crate_versions)]
 #![doc = include_str!("../README.md")]
 
 use reqwest::{
-    Client as Http, StatusCode,
-    header::{self, HeaderMap, HeaderValue, HeaderName},
+    Client as Http, Method, RequestBuilder,
+    header::{self, HeaderMap, HeaderName, HeaderValue},
 };
-use serde_json::json;
-use std::env;
-use types::{Error, Include, InputItemList, Request, Response, ResponseResult};
+use schema::SchemaLimits;
+use serde::de::DeserializeOwned;
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+use types::{Include, InputItemList, InputItemListParams, Model, Request, Response};
 #[cfg(feature = "stream")]
 use {
     async_fn_stream::try_fn_stream,
+    eventsource_stream::{EventStreamError, Eventsource},
     futures::{Stream, StreamExt},
-    reqwest_eventsource::{Event as EventSourceEvent, RequestBuilderExt},
-    types::Event,
+    types::{Event, InputItem},
 };
 
 /// Types for interacting with the Responses API.
 pub mod types;
 
+/// Normalization and validation of JSON schemas for [strict mode](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas).
+pub mod schema;
+
+mod azure;
+mod error;
+mod metadata;
+#[cfg(feature = "schemars")]
+mod parsed;
+mod partial;
+mod retry;
+mod shell;
+
+pub use azure::{AzureConfig, DEFAULT_AZURE_API_VERSION};
+pub use error::{ApiError, ArgumentsError, ClientError};
+pub use metadata::{RateLimits, ResponseMetadata};
+#[cfg(feature = "schemars")]
+pub use parsed::ParsedResponse;
+#[cfg(feature = "stream")]
+pub use partial::partial_outputs;
+pub use partial::{Partial, parse_partial_json};
+#[cfg(feature = "stream")]
+pub use retry::ReconnectPolicy;
+pub use retry::RetryPolicy;
+#[cfg(feature = "local-shell")]
+pub use shell::LocalShellExecutor;
+pub use shell::{ShellError, ShellExecutor, ShellOutput};
+
+/// The base URL used when none is configured.
+pub const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+
 /// The OpenAI Responses API Client.
 #[derive(Debug, Clone)]
 pub struct Client {
-    client: reqwest::Client,
+    http: Http,
+    base_url: String,
+    azure: Option<AzureConfig>,
+    retry_policy: RetryPolicy,
+    schema_limits: SchemaLimits,
+    validate_schemas: bool,
+    #[cfg(feature = "stream")]
+    reconnect_policy: ReconnectPolicy,
 }
 
 /// Errors that can occur when creating a new Client.
@@ -40,22 +81,78 @@ pub enum CreateError {
     /// Could not retrieve the ``OPENAI_API_KEY`` env var
     #[error("Could not retrieve the $OPENAI_API_KEY env var")]
     ApiKeyNotFound,
+    /// The provided base URL is not a valid absolute URL.
+    #[error("The provided base URL is not a valid absolute URL: {0}")]
+    InvalidBaseUrl(String),
 }
 
+/// Errors that can occur while streaming a response.
 #[cfg(feature = "stream")]
 #[derive(Debug, thiserror::Error)]
 pub enum StreamError {
+    /// The API responded with an error instead of opening the stream, e.g. because the context length was exceeded.
     #[error("{0}")]
-    Stream(#[from] reqwest_eventsource::Error),
+    Api(Box<ApiError>),
+    /// The stream could not be opened.
+    #[error("{0}")]
+    Client(ClientError),
+    /// The connection failed or the server sent a malformed event stream.
+    #[error("{0}")]
+    Stream(#[from] EventStreamError<reqwest::Error>),
+    /// An event could not be deserialized. The stream continues with the next event.
     #[error("Failed to parse event data: {0}")]
     Parsing(#[from] serde_json::Error),
+    /// The connection was closed before the response finished.
+    #[error("The connection was closed before the response finished")]
+    ConnectionClosed,
+}
+
+#[cfg(feature = "stream")]
+impl StreamError {
+    /// The HTTP status code returned by the API when the stream failed to open, if any.
+    #[must_use]
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Api(error) => Some(error.status),
+            Self::Client(error) => error.status(),
+            _ => None,
+        }
+    }
+
+    /// The OpenAI error object returned by the API when the stream failed to open, if any.
+    #[must_use]
+    pub fn api_error(&self) -> Option<&types::Error> {
+        match self {
+            Self::Api(error) => error.error.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The `x-request-id` of the failed request, if any.
+    #[must_use]
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Api(error) => error.request_id.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+impl From<ClientError> for StreamError {
+    fn from(error: ClientError) -> Self {
+        match error {
+            ClientError::Api(error) => Self::Api(error),
+            error => Self::Client(error),
+        }
+    }
 }
 
 /// Builder for constructing a [`Client`] with optional OpenAI specific headers.
 ///
 /// The builder lets you supply the mandatory API key plus the optional
-/// `OpenAI-Organization` and `OpenAI-Project` headers in a single fluent
-/// chain. If you only need to provide the API key you may continue to use
+/// `OpenAI-Organization` and `OpenAI-Project` headers and a custom base URL
+/// in a single fluent chain. If you only need to provide the API key you may continue to use
 /// [`Client::new`] or [`Client::from_env`]; the builder is the most flexible
 /// entry-point.
 ///
@@ -75,6 +172,13 @@ pub struct ClientBuilder {
     api_key: Option<String>,
     organization: Option<String>,
     project: Option<String>,
+    base_url: Option<String>,
+    azure: Option<AzureConfig>,
+    retry_policy: Option<RetryPolicy>,
+    schema_limits: Option<SchemaLimits>,
+    validate_schemas: Option<bool>,
+    #[cfg(feature = "stream")]
+    reconnect_policy: ReconnectPolicy,
 }
 
 impl ClientBuilder {
@@ -105,22 +209,92 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the base URL requests are sent to. Defaults to [`DEFAULT_BASE_URL`].
+    ///
+    /// Useful for routing traffic through a proxy or gateway, or for targeting an OpenAI-compatible server.
+    /// The URL should include the API version prefix, e.g. `https://my-gateway.example.com/v1`.
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Targets Azure OpenAI instead of OpenAI.
+    ///
+    /// The API key is sent in an `api-key` header instead of `Authorization: Bearer`, the `api-version` query parameter is added to every request,
+    /// and requests are sent to the Azure resource endpoint. An explicitly configured [`base_url`](Self::base_url) still takes precedence.
+    #[must_use]
+    pub fn azure(mut self, azure: AzureConfig) -> Self {
+        self.azure = Some(azure);
+        self
+    }
+
+    /// Sets the policy for retrying failed requests. Defaults to [`RetryPolicy::default`].
+    ///
+    /// Use [`RetryPolicy::never`] to disable retries.
+    #[must_use]
+    pub const fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the limits strict schemas are checked against before a request is sent. Defaults to [`SchemaLimits::default`].
+    #[must_use]
+    pub const fn schema_limits(mut self, schema_limits: SchemaLimits) -> Self {
+        self.schema_limits = Some(schema_limits);
+        self
+    }
+
+    /// Sets whether strict schemas are checked before a request is sent. Defaults to `true`.
+    ///
+    /// Disable this to skip the check on every request, for example when the schemas are known to be valid.
+    #[must_use]
+    pub const fn validate_schemas(mut self, validate_schemas: bool) -> Self {
+        self.validate_schemas = Some(validate_schemas);
+        self
+    }
+
+    #[cfg(feature = "stream")]
+    /// Sets whether streams reconnect when the connection drops mid-response. Defaults to [`ReconnectPolicy::Never`].
+    #[must_use]
+    pub const fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
     /// Finalises the builder, returning a [`Client`].
     ///
     /// # Errors
     ///
     /// - `CreateError::ApiKeyNotFound` if no API key was provided.
     /// - `CreateError::InvalidApiKey` if any header value contains invalid characters.
+    /// - `CreateError::InvalidBaseUrl` if the base URL is not a valid absolute URL.
     /// - `CreateError::CouldNotCreateClient` if the underlying HTTP client could not be created.
     pub fn build(self) -> Result<Client, CreateError> {
         let api_key = self.api_key.ok_or(CreateError::ApiKeyNotFound)?;
 
+        let base_url = match (self.base_url, &self.azure) {
+            (Some(url), _) => url.trim_end_matches('/').to_string(),
+            (None, Some(azure)) => azure.base_url(),
+            (None, None) => DEFAULT_BASE_URL.to_string(),
+        };
+        if reqwest::Url::parse(&base_url).is_err() {
+            return Err(CreateError::InvalidBaseUrl(base_url));
+        }
+
         // Build the default headers
-        let mut headers = HeaderMap::from_iter([(
-            header::AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {api_key}"))
-                .map_err(|_| CreateError::InvalidApiKey)?,
-        )]);
+        let mut headers = HeaderMap::from_iter([if self.azure.is_some() {
+            (
+                HeaderName::from_static("api-key"),
+                HeaderValue::from_str(&api_key).map_err(|_| CreateError::InvalidApiKey)?,
+            )
+        } else {
+            (
+                header::AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {api_key}"))
+                    .map_err(|_| CreateError::InvalidApiKey)?,
+            )
+        }]);
 
         if let Some(org) = self.organization {
             headers.insert(
@@ -136,9 +310,18 @@ impl ClientBuilder {
             );
         }
 
-        let client = Http::builder().default_headers(headers).build()?;
+        let http = Http::builder().default_headers(headers).build()?;
 
-        Ok(Client { client })
+        Ok(Client {
+            http,
+            base_url,
+            azure: self.azure,
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            schema_limits: self.schema_limits.unwrap_or_default(),
+            validate_schemas: self.validate_schemas.unwrap_or(true),
+            #[cfg(feature = "stream")]
+            reconnect_policy: self.reconnect_policy,
+        })
     }
 }
 
@@ -169,27 +352,136 @@ impl Client {
     /// - `CreateError::CouldNotCreateClient` if the HTTP Client could not be created.
     /// - `CreateError::InvalidApiKey` if the API key contains invalid header value characters.
     pub fn new(api_key: &str) -> Result<Self, CreateError> {
-        let client = Http::builder()
-            .default_headers(HeaderMap::from_iter([(
-                header::AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {api_key}"))
-                    .map_err(|_| CreateError::InvalidApiKey)?,
-            )]))
-            .build()?;
-
-        Ok(Self { client })
+        ClientBuilder::new().api_key(api_key).build()
     }
 
     /// Creates a new Client from the `OPENAI_API_KEY` environment variable.
     ///
+    /// If the `OPENAI_BASE_URL` environment variable is set, it is used as the base URL.
+    ///
     /// # Errors
     /// - `CreateError::CouldNotCreateClient` if the HTTP Client could not be created.
     /// - `CreateError::InvalidApiKey` if the API key contains invalid header value characters.
+    /// - `CreateError::InvalidBaseUrl` if `OPENAI_BASE_URL` is not a valid absolute URL.
     /// - `CreateError::ApiKeyNotFound` if the `OPENAI_API_KEY` environment variable is not set or contains an equal sign or NUL (`'='` or `'\0'`).
     pub fn from_env() -> Result<Self, CreateError> {
         let api_key = env::var("OPENAI_API_KEY").map_err(|_| CreateError::ApiKeyNotFound)?;
 
-        Self::new(&api_key)
+        let mut builder = ClientBuilder::new().api_key(api_key);
+        if let Ok(base_url) = env::var("OPENAI_BASE_URL") {
+            builder = builder.base_url(base_url);
+        }
+
+        builder.build()
+    }
+
+    /// The base URL requests are sent to.
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Builds the full URL for the given path under the base URL.
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// Starts a request to the given path, adding the Azure `api-version` query parameter if needed.
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let builder = self.http.request(method, self.url(path));
+
+        match &self.azure {
+            Some(azure) => builder.query(&[("api-version", &azure.api_version)]),
+            None => builder,
+        }
+    }
+
+    /// Sends a request, retrying transient failures according to the client's [`RetryPolicy`].
+    async fn send(&self, builder: RequestBuilder) -> Result<reqwest::Response, reqwest::Error> {
+        let mut attempt = 0;
+
+        loop {
+            // Builders that can't be cloned carry a construction error, which sending surfaces.
+            let Some(request) = builder.try_clone() else {
+                return builder.send().await;
+            };
+
+            let delay = match request.send().await {
+                Ok(response)
+                    if self.retry_policy.can_retry(attempt)
+                        && RetryPolicy::should_retry_response(
+                            response.status(),
+                            response.headers(),
+                        ) =>
+                {
+                    self.retry_policy.delay(attempt, Some(response.headers()))
+                }
+                Err(error)
+                    if self.retry_policy.can_retry(attempt)
+                        && RetryPolicy::should_retry_error(&error) =>
+                {
+                    self.retry_policy.delay(attempt, None)
+                }
+                result => return result,
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Turns a non-2xx response into a [`ClientError::Api`], reading the error body.
+    async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, ClientError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+
+        Err(ApiError::new(status, &headers, body).into())
+    }
+
+    /// Checks the status of a response and deserializes its body.
+    async fn parse<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, ClientError> {
+        Self::parse_with_metadata(response)
+            .await
+            .map(|(value, _)| value)
+    }
+
+    /// Checks the status of a response and deserializes its body, keeping its [`ResponseMetadata`].
+    async fn parse_with_metadata<T: DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<(T, ResponseMetadata), ClientError> {
+        let response = Self::check_status(response).await?;
+        let metadata = ResponseMetadata::from_response(&response);
+        let body = response.text().await?;
+
+        let value =
+            serde_json::from_str(&body).map_err(|error| ClientError::Parsing { error, body })?;
+
+        Ok((value, metadata))
+    }
+
+    /// Checks the strict schemas in a request against the client's [`SchemaLimits`], so unsupported schemas fail before the request is sent.
+    fn validate(&self, request: &Request) -> Result<(), ClientError> {
+        if !self.validate_schemas {
+            return Ok(());
+        }
+
+        Ok(request.validate_strict_schemas(&self.schema_limits)?)
+    }
+
+    /// Applies client-wide overrides to an outgoing request, such as the Azure deployment name.
+    fn prepare(&self, request: &mut Request) {
+        if let Some(deployment) = self
+            .azure
+            .as_ref()
+            .and_then(|azure| azure.deployment.as_ref())
+        {
+            request.model = Model::Other(deployment.clone());
+        }
     }
 
     /// Creates a model response.
@@ -200,26 +492,57 @@ impl Client {
     ///
     /// ## Errors
     ///
-    /// Errors if the request fails to send or has a non-200 status code (except for 400, which will return an OpenAI error instead).
-    pub async fn create(
+    /// Errors if the request fails to send, the API returns an error, or the response cannot be parsed. See [`ClientError`].
+    pub async fn create(&self, request: Request) -> Result<Response, ClientError> {
+        self.create_with_metadata(request)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// Creates a model response, returning it along with the [`ResponseMetadata`] of the HTTP response,
+    /// such as the request ID and rate limit state.
+    ///
+    /// See [`Client::create`] for more information.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the request fails to send, the API returns an error, or the response cannot be parsed. See [`ClientError`].
+    pub async fn create_with_metadata(
         &self,
         mut request: Request,
-    ) -> Result<Result<Response, Error>, reqwest::Error> {
+    ) -> Result<(Response, ResponseMetadata), ClientError> {
         // Use the `stream` function to stream the response.
         request.stream = Some(false);
+        self.validate(&request)?;
+        self.prepare(&mut request);
 
-        let mut response = self
-            .client
-            .post("https://api.openai.com/v1/responses")
-            .json(&request)
-            .send()
+        let response = self
+            .send(self.request(Method::POST, "/responses").json(&request))
             .await?;
 
-        if response.status() != StatusCode::BAD_REQUEST {
-            response = response.error_for_status()?;
-        }
+        Self::parse_with_metadata(response).await
+    }
+
+    #[cfg(feature = "schemars")]
+    /// Creates a model response whose output follows the JSON schema of `T`, and parses it.
+    ///
+    /// Sets `text.format` to the strict-mode schema of `T`, replacing any format set on the request.
+    /// Tools in the request are left untouched, so if the model calls them instead of responding,
+    /// [`ParsedResponse::output_parsed`] is `None` and the calls can be found in the response's output.
+    ///
+    /// See [`Client::create`] for more information.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the request fails to send, the API returns an error, or the response cannot be parsed. See [`ClientError`].
+    /// If the model refuses to respond, errors with [`ClientError::Refusal`]. If its output doesn't match `T`, errors with [`ClientError::InvalidOutput`].
+    pub async fn create_parsed<T: schemars::JsonSchema + DeserializeOwned>(
+        &self,
+        mut request: Request,
+    ) -> Result<ParsedResponse<T>, ClientError> {
+        request.text.get_or_insert_default().format = types::TextFormat::json_schema::<T>();
 
-        response.json::<ResponseResult>().await.map(Into::into)
+        ParsedResponse::new(self.create(request).await?)
     }
 
     #[cfg(feature = "stream")]
@@ -229,35 +552,124 @@ impl Client {
     /// Have the model call your own [custom code](https://platform.openai.com/docs/guides/function-calling) or use built-in [tools](https://platform.openai.com/docs/guides/tools) like [web search](https://platform.openai.com/docs/guides/tools-web-search) or [file search](https://platform.openai.com/docs/guides/tools-file-search) to use your own data as input for the model's response.
     ///
     /// To receive the response as a regular HTTP response, use the `create` function.
-    pub fn stream(&self, mut request: Request) -> impl Stream<Item = Result<Event, StreamError>> {
-        // Use the `create` function to receive a regular HTTP response.
-        request.stream = Some(true);
-
-        let mut event_source = self
-            .client
-            .post("https://api.openai.com/v1/responses")
-            .json(&request)
-            .eventsource()
-            .unwrap_or_else(|_| unreachable!("Body is never a stream"));
+    ///
+    /// Failures to open the stream are retried according to the client's [`RetryPolicy`], as long as no event has been received yet.
+    ///
+    /// If the connection drops before the response finishes, the stream ends with [`StreamError::ConnectionClosed`] or [`StreamError::Stream`],
+    /// unless the client's [`ReconnectPolicy`] allows reconnecting. Errors opening the stream, and error responses from the API, always end the stream.
+    /// A reconnection that fails with a connection error or a retryable status counts as another attempt, and the last error ends the stream once
+    /// attempts run out; any other failed reconnection ends the stream with its error.
+    pub fn stream(&self, request: Request) -> impl Stream<Item = Result<Event, StreamError>> {
+        let client = self.clone();
 
         let stream = try_fn_stream(|emitter| async move {
-            while let Some(event) = event_source.next().await {
-                let message = match event {
-                    Ok(EventSourceEvent::Open) => continue,
-                    Ok(EventSourceEvent::Message(message)) => message,
-                    Err(error) => {
-                        if matches!(error, reqwest_eventsource::Error::StreamEnded) {
-                            break;
+            let response = client.open_stream(request.clone()).await?;
+
+            let mut events = Box::pin(parse_events(response));
+            let mut response_id = None;
+            let mut last_sequence_number = None;
+            let mut received_event = false;
+            let mut attempt = 0;
+            let mut reconnect_error = None;
+
+            loop {
+                let next = match reconnect_error.take() {
+                    Some(error) => Some(Err(error)),
+                    None => events.next().await,
+                };
+                let error = match next {
+                    Some(Ok(event)) => {
+                        attempt = 0;
+                        received_event = true;
+                        if let Some(response) = event.response() {
+                            response_id = Some(response.id.clone());
+                        }
+                        if let Some(sequence_number) = event.sequence_number() {
+                            last_sequence_number = Some(sequence_number);
                         }
 
-                        emitter.emit_err(StreamError::Stream(error)).await;
+                        let is_terminal = event.is_terminal();
+                        emitter.emit(event).await;
+                        if is_terminal {
+                            break;
+                        }
                         continue;
                     }
+                    Some(Err(StreamError::Parsing(error))) => {
+                        emitter.emit_err(StreamError::Parsing(error)).await;
+                        continue;
+                    }
+                    Some(Err(error)) => error,
+                    // The stream should always end with a terminal event.
+                    None => StreamError::ConnectionClosed,
+                };
+
+                let can_reconnect = match client.reconnect_policy {
+                    ReconnectPolicy::Never => false,
+                    ReconnectPolicy::Bounded { max_attempts } => {
+                        !received_event && attempt < max_attempts
+                    }
+                    ReconnectPolicy::Resume { max_attempts } => {
+                        response_id.is_some() && attempt < max_attempts
+                    }
+                };
+                if !can_reconnect {
+                    emitter.emit_err(error).await;
+                    break;
+                }
+
+                tokio::time::sleep(client.retry_policy.delay(attempt, None)).await;
+                attempt += 1;
+
+                let response = match (&client.reconnect_policy, &response_id) {
+                    (ReconnectPolicy::Resume { .. }, Some(response_id)) => {
+                        client
+                            .open_resumed_stream(response_id, last_sequence_number)
+                            .await
+                    }
+                    _ => client.open_stream(request.clone()).await,
                 };
+                // A failed reconnect counts as another attempt, ending the stream once they run out.
+                // Errors another attempt won't fix, such as a 4xx from the API, end it right away.
+                match response {
+                    Ok(response) => events = Box::pin(parse_events(response)),
+                    Err(error) if error.is_transient() => reconnect_error = Some(error.into()),
+                    Err(error) => {
+                        emitter.emit_err(error.into()).await;
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        Box::pin(stream)
+    }
+
+    #[cfg(feature = "stream")]
+    /// Streams the events of a background response, starting after the event with the given sequence number.
+    ///
+    /// Use this to pick up a stream that was interrupted, passing the `sequence_number` of the last event you received.
+    /// If `starting_after` is `None`, the stream starts from the first event. Only responses created with `background` set to `true` can be streamed again.
+    pub fn resume_stream(
+        &self,
+        response_id: &str,
+        starting_after: Option<u64>,
+    ) -> impl Stream<Item = Result<Event, StreamError>> {
+        let client = self.clone();
+        let response_id = response_id.to_string();
+
+        let stream = try_fn_stream(|emitter| async move {
+            let response = client
+                .open_resumed_stream(&response_id, starting_after)
+                .await?;
 
-                match serde_json::from_str::<Event>(&message.data) {
+            let mut events = parse_events(response);
+            while let Some(event) = events.next().await {
+                match event {
                     Ok(event) => emitter.emit(event).await,
-                    Err(error) => emitter.emit_err(StreamError::Parsing(error)).await,
+                    Err(error) => emitter.emit_err(error).await,
                 }
             }
 
@@ -267,60 +679,225 @@ impl Client {
         Box::pin(stream)
     }
 
+    #[cfg(feature = "stream")]
+    /// Creates a model response and streams it back as it is generated, along with the [`ResponseMetadata`] of the initial HTTP response.
+    ///
+    /// See [`Client::stream`] for more information.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the stream could not be opened. See [`ClientError`].
+    pub async fn stream_with_metadata(
+        &self,
+        request: Request,
+    ) -> Result<
+        (
+            impl Stream<Item = Result<Event, StreamError>> + use<>,
+            ResponseMetadata,
+        ),
+        ClientError,
+    > {
+        let response = self.open_stream(request).await?;
+        let metadata = ResponseMetadata::from_response(&response);
+
+        Ok((Box::pin(parse_events(response)), metadata))
+    }
+
+    #[cfg(feature = "stream")]
+    /// Sends a streaming request, returning the successful HTTP response whose body contains the server-sent events.
+    async fn open_stream(&self, mut request: Request) -> Result<reqwest::Response, ClientError> {
+        // Use the `create` function to receive a regular HTTP response.
+        request.stream = Some(true);
+        self.validate(&request)?;
+        self.prepare(&mut request);
+
+        let response = self
+            .send(self.request(Method::POST, "/responses").json(&request))
+            .await?;
+
+        Self::check_status(response).await
+    }
+
+    #[cfg(feature = "stream")]
+    /// Requests the events of a stored response after the given sequence number, returning the HTTP response whose body contains them.
+    async fn open_resumed_stream(
+        &self,
+        response_id: &str,
+        starting_after: Option<u64>,
+    ) -> Result<reqwest::Response, ClientError> {
+        let mut builder = self
+            .request(Method::GET, &format!("/responses/{response_id}"))
+            .query(&[("stream", "true")]);
+        if let Some(starting_after) = starting_after {
+            builder = builder.query(&[("starting_after", starting_after)]);
+        }
+
+        let response = self.send(builder).await?;
+
+        Self::check_status(response).await
+    }
+
     /// Retrieves a model response with the given ID.
     ///
     /// ## Errors
     ///
-    /// Errors if the request fails to send or has a non-200 status code (except for 400, which will return an OpenAI error instead).
+    /// Errors if the request fails to send, the API returns an error, or the response cannot be parsed. See [`ClientError`].
     pub async fn get(
         &self,
         response_id: &str,
-        include: Option<Include>,
-    ) -> Result<Result<Response, Error>, reqwest::Error> {
-        let mut response = self
-            .client
-            .get(format!("https://api.openai.com/v1/responses/{response_id}"))
-            .query(&json!({ "include": include }))
-            .send()
+        include: Option<Vec<Include>>,
+    ) -> Result<Response, ClientError> {
+        self.get_with_metadata(response_id, include)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// Retrieves a model response with the given ID, along with the [`ResponseMetadata`] of the HTTP response.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the request fails to send, the API returns an error, or the response cannot be parsed. See [`ClientError`].
+    pub async fn get_with_metadata(
+        &self,
+        response_id: &str,
+        include: Option<Vec<Include>>,
+    ) -> Result<(Response, ResponseMetadata), ClientError> {
+        let query = include
+            .iter()
+            .flatten()
+            .map(|include| ("include[]", types::query_value(include)))
+            .collect::<Vec<_>>();
+
+        let response = self
+            .send(
+                self.request(Method::GET, &format!("/responses/{response_id}"))
+                    .query(&query),
+            )
             .await?;
 
-        if response.status() != StatusCode::BAD_REQUEST {
-            response = response.error_for_status()?;
-        }
+        Self::parse_with_metadata(response).await
+    }
+
+    /// Cancels a model response with the given ID. Only responses created with `background` set to `true` can be cancelled.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the request fails to send, the API returns an error, or the response cannot be parsed. See [`ClientError`].
+    pub async fn cancel(&self, response_id: &str) -> Result<Response, ClientError> {
+        let response = self
+            .send(self.request(Method::POST, &format!("/responses/{response_id}/cancel")))
+            .await?;
+
+        Self::parse(response).await
+    }
+
+    /// Polls a model response with the given ID until it reaches a terminal status (`completed`, `failed`, `incomplete` or `cancelled`).
+    ///
+    /// This is useful for responses created with `background` set to `true`, which return immediately with a `queued` status.
+    /// The response is retrieved every `poll_interval`, giving up after `timeout` if one is provided.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if a poll fails, or with `ClientError::Timeout` if the response did not finish in time. See [`ClientError`].
+    pub async fn wait_for_completion(
+        &self,
+        response_id: &str,
+        poll_interval: Duration,
+        timeout: Option<Duration>,
+    ) -> Result<Response, ClientError> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+        loop {
+            let response = self.get(response_id, None).await?;
+            if response.status.is_terminal() {
+                return Ok(response);
+            }
+
+            let delay = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        return Err(ClientError::Timeout {
+                            response_id: response_id.to_string(),
+                        });
+                    }
+
+                    poll_interval.min(remaining)
+                }
+                None => poll_interval,
+            };
 
-        response.json::<ResponseResult>().await.map(Into::into)
+            tokio::time::sleep(delay).await;
+        }
     }
 
     /// Deletes a model response with the given ID.
     ///
     /// ## Errors
     ///
-    /// Errors if the request fails to send or has a non-200 status code.
-    pub async fn delete(&self, response_id: &str) -> Result<(), reqwest::Error> {
-        self.client
-            .delete(format!("https://api.openai.com/v1/responses/{response_id}"))
-            .send()
-            .await?
-            .error_for_status()?;
+    /// Errors if the request fails to send or the API returns an error. See [`ClientError`].
+    pub async fn delete(&self, response_id: &str) -> Result<(), ClientError> {
+        let response = self
+            .send(self.request(Method::DELETE, &format!("/responses/{response_id}")))
+            .await?;
+
+        Self::check_status(response).await?;
 
         Ok(())
     }
 
-    /// Returns a list of input items for a given response.
+    /// Returns a page of input items for a given response.
+    ///
+    /// Use the `after` and `before` cursors in `params` to paginate, or [`Client::list_all_inputs`] to iterate over every item.
     ///
     /// ## Errors
     ///
-    /// Errors if the request fails to send or has a non-200 status code.
-    pub async fn list_inputs(&self, response_id: &str) -> Result<InputItemList, reqwest::Error> {
-        self.client
-            .get(format!(
-                "https://api.openai.com/v1/responses/{response_id}/inputs"
-            ))
-            .send()
-            .await?
-            .error_for_status()?
-            .json()
-            .await
+    /// Errors if the request fails to send, the API returns an error, or the response cannot be parsed. See [`ClientError`].
+    pub async fn list_inputs(
+        &self,
+        response_id: &str,
+        params: &InputItemListParams,
+    ) -> Result<InputItemList, ClientError> {
+        let response = self
+            .send(
+                self.request(Method::GET, &format!("/responses/{response_id}/inputs"))
+                    .query(&params.query()),
+            )
+            .await?;
+
+        Self::parse(response).await
+    }
+
+    #[cfg(feature = "stream")]
+    /// Returns every input item for a given response, fetching further pages as needed.
+    ///
+    /// The `after` cursor in `params` sets where to start; the other params apply to every page.
+    pub fn list_all_inputs(
+        &self,
+        response_id: &str,
+        mut params: InputItemListParams,
+    ) -> impl Stream<Item = Result<InputItem, ClientError>> {
+        let client = self.clone();
+        let response_id = response_id.to_string();
+
+        let stream = try_fn_stream(|emitter| async move {
+            loop {
+                let page = client.list_inputs(&response_id, &params).await?;
+
+                for item in page.data {
+                    emitter.emit(item).await;
+                }
+
+                match page.last_id {
+                    Some(last_id) if page.has_more => params.after = Some(last_id),
+                    _ => break,
+                }
+            }
+
+            Ok(())
+        });
+
+        Box::pin(stream)
     }
 
     /// Sends the request and returns the raw response body **without** attempting to deserialize it.
@@ -331,19 +908,23 @@ impl Client {
     /// what was actually received from the server.
     ///
     /// NOTE: Because this function is meant only for debugging it intentionally returns the
-    /// body as a plain `String` instead of the strongly-typed [`Response`] / [`Error`] types.
+    /// body as a plain `String` instead of the strongly-typed [`Response`] / [`ClientError`] types.
     /// It otherwise behaves exactly like [`Client::create`]: it sets `stream = false` on the
-    /// request and sends it to `https://api.openai.com/v1/responses`.
-    pub async fn create_raw(
+    /// request and sends it to the `/responses` endpoint under the configured base URL.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the request fails to send or the body cannot be read.
+    pub async fn create_raw(
         &self,
         mut request: Request,
     ) -> Result<(reqwest::StatusCode, String), reqwest::Error> {
         // Ensure we get a regular HTTP response (not SSE stream)
         request.stream = Some(false);
+        self.prepare(&mut request);
 
         let resp = self
-            .client
-            .post("https://api.openai.com/v1/responses")
+            .request(Method::POST, "/responses")
             .json(&request)
             .send()
             .await?;
@@ -358,9 +939,21 @@ impl Client {
     }
 }
 
+/// Parses the server-sent events in the body of a streaming response.
+#[cfg(feature = "stream")]
+fn parse_events(response: reqwest::Response) -> impl Stream<Item = Result<Event, StreamError>> {
+    response.bytes_stream().eventsource().map(|event| {
+        let event = event?;
+
+        serde_json::from_str::<Event>(&event.data).map_err(StreamError::Parsing)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reqwest::StatusCode;
+    use serde_json::json;
 
     #[test]
     fn builder_without_api_key_fails() {
@@ -377,40 +970,1527 @@ mod tests {
         assert!(matches!(result.unwrap_err(), CreateError::InvalidApiKey));
     }
 
+    #[test]
+    fn builder_rejects_invalid_base_url() {
+        let result = Client::builder()
+            .api_key("sk-test")
+            .base_url("not a url")
+            .build();
+        assert!(matches!(
+            result.unwrap_err(),
+            CreateError::InvalidBaseUrl(_)
+        ));
+    }
+
+    #[test]
+    fn builder_normalises_base_url() {
+        let client = Client::builder().api_key("sk-test").build().unwrap();
+        assert_eq!(client.base_url(), DEFAULT_BASE_URL);
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url("http://localhost:8080/v1/")
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url(), "http://localhost:8080/v1");
+        assert_eq!(
+            client.url("/responses"),
+            "http://localhost:8080/v1/responses"
+        );
+    }
+
+    #[test]
+    fn message_input_items_deserialize_as_input_messages() {
+        let item: types::InputItem = serde_json::from_value(json!({
+            "type": "message",
+            "role": "user",
+            "content": { "type": "input_text", "text": "Hi" },
+        }))
+        .unwrap();
+
+        assert!(matches!(item, types::InputItem::InputMessage(_)));
+    }
+
     #[tokio::test]
     async fn builder_sends_all_headers_over_wire() {
-        use wiremock::{Mock, MockServer, ResponseTemplate};
         use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
 
         // Start an ephemeral server.
         let server = MockServer::start().await;
 
         // Register expectation: we should receive all headers.
-        Mock::given(method("GET"))
-            .and(path("/"))
+        Mock::given(method("DELETE"))
+            .and(path("/v1/responses/resp_123"))
             .and(header("authorization", "Bearer sk-test"))
             .and(header("openai-organization", "my-org"))
             .and(header("openai-project", "my-proj"))
             .respond_with(ResponseTemplate::new(200))
+            .expect(1)
             .mount(&server)
             .await;
 
-        // Build our client with all optional headers.
+        // Build our client with all optional headers, pointed at the mock server.
         let client = Client::builder()
             .api_key("sk-test")
             .organization("my-org")
             .project("my-proj")
+            .base_url(format!("{}/v1", server.uri()))
             .build()
             .unwrap();
 
-        // Make a simple GET request to the mock server.
-        let resp = client
-            .client
-            .get(server.uri())
-            .send()
+        client.delete("resp_123").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn api_errors_carry_status_body_and_headers() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .insert_header("x-request-id", "req_abc")
+                    .insert_header("x-ratelimit-remaining-requests", "42")
+                    .set_body_json(json!({
+                        "error": {
+                            "type": "invalid_request_error",
+                            "message": "No response found with id 'resp_123'.",
+                            "code": null,
+                            "param": null
+                        }
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let error = client.get("resp_123", None).await.unwrap_err();
+        assert_eq!(error.status(), Some(StatusCode::NOT_FOUND));
+        assert_eq!(
+            error.api_error().unwrap().message,
+            "No response found with id 'resp_123'."
+        );
+
+        let ClientError::Api(error) = error else {
+            panic!("expected an API error, got {error:?}");
+        };
+        assert_eq!(error.request_id.as_deref(), Some("req_abc"));
+        assert_eq!(error.rate_limits.remaining_requests, Some(42));
+        assert!(error.body.contains("invalid_request_error"));
+    }
+
+    #[tokio::test]
+    async fn unparseable_bodies_are_reported_with_the_raw_body() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let error = client.get("resp_123", None).await.unwrap_err();
+        assert!(matches!(error, ClientError::Parsing { body, .. } if body == "not json"));
+    }
+
+    fn response_json() -> serde_json::Value {
+        json!({
+            "id": "resp_123",
+            "object": "response",
+            "created_at": 1_741_476_542,
+            "status": "completed",
+            "model": "gpt-4o-2024-08-06",
+            "output": [],
+            "metadata": {},
+            "parallel_tool_calls": true,
+            "reasoning": {},
+            "store": true,
+            "temperature": 1.0,
+            "text": { "format": { "type": "text" } },
+            "tool_choice": "auto",
+            "tools": [],
+            "top_p": 1.0,
+            "truncation": "disabled"
+        })
+    }
+
+    #[test]
+    fn unknown_types_round_trip() {
+        let item = json!({ "type": "brand_new_call", "id": "bn_123", "payload": { "a": [1, 2] } });
+        let tool = json!({ "type": "brand_new_tool", "option": true });
+        let content = json!({
+            "type": "output_text",
+            "text": "Hi",
+            "annotations": [{ "type": "brand_new_citation", "start_index": 0 }],
+        });
+        let message = json!({
+            "type": "message",
+            "id": "msg_123",
+            "role": "assistant",
+            "status": "completed",
+            "content": [content, { "type": "output_audio", "data": "..." }],
+        });
+
+        let mut body = response_json();
+        body["output"] = json!([message, item]);
+        body["tools"] = json!([tool]);
+
+        let response: Response = serde_json::from_value(body.clone()).unwrap();
+        assert_eq!(response.output_text(), "Hi");
+        assert!(
+            matches!(&response.output[1], types::OutputItem::Unknown { r#type, .. } if r#type == "brand_new_call")
+        );
+        assert!(
+            matches!(&response.tools[0], types::Tool::Unknown { r#type, .. } if r#type == "brand_new_tool")
+        );
+        assert_eq!(
+            serde_json::to_value(&response).unwrap()["output"],
+            body["output"]
+        );
+        assert_eq!(
+            serde_json::to_value(&response).unwrap()["tools"],
+            body["tools"]
+        );
+
+        let input: types::InputItem = serde_json::from_value(item.clone()).unwrap();
+        assert_eq!(serde_json::to_value(input).unwrap(), item);
+    }
+
+    #[test]
+    fn malformed_known_types_are_errors() {
+        let item = json!({ "type": "function_call", "call_id": "call_123" });
+        assert!(serde_json::from_value::<types::OutputItem>(item).is_err());
+
+        let tool = json!({ "type": "file_search" });
+        assert!(serde_json::from_value::<types::Tool>(tool).is_err());
+
+        #[cfg(feature = "stream")]
+        {
+            let event = json!({ "type": "response.completed", "sequence_number": 1 });
+            assert!(serde_json::from_value::<Event>(event).is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn create_with_metadata_reads_response_headers() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-request-id", "req_abc")
+                    .insert_header("openai-processing-ms", "1234")
+                    .insert_header("x-ratelimit-remaining-tokens", "29000")
+                    .set_body_json(response_json()),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let (response, metadata) = client
+            .create_with_metadata(Request::default())
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, "resp_123");
+        assert_eq!(metadata.status, StatusCode::OK);
+        assert_eq!(metadata.request_id.as_deref(), Some("req_abc"));
+        assert_eq!(metadata.processing_time, Some(Duration::from_millis(1234)));
+        assert_eq!(metadata.rate_limits.remaining_tokens, Some(29000));
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_with_metadata_reads_response_headers() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        let event = json!({ "type": "response.completed", "response": response_json() });
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-request-id", "req_abc")
+                    .set_body_raw(format!("data: {event}\n\n"), "text/event-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let (stream, metadata) = client
+            .stream_with_metadata(Request::default())
+            .await
+            .unwrap();
+        assert_eq!(metadata.request_id.as_deref(), Some("req_abc"));
+
+        let events = stream.collect::<Vec<_>>().await;
+        assert!(
+            matches!(&events[..], [Ok(Event::ResponseCompleted { response, .. })] if response.id == "resp_123")
+        );
+    }
+
+    #[tokio::test]
+    async fn wait_for_completion_polls_until_terminal_status() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        for status in ["queued", "in_progress"] {
+            let mut body = response_json();
+            body["status"] = json!(status);
+
+            Mock::given(method("GET"))
+                .and(path("/responses/resp_123"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .up_to_n_times(1)
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let response = client
+            .wait_for_completion("resp_123", Duration::from_millis(1), None)
+            .await
+            .unwrap();
+        assert_eq!(response.status, types::ResponseStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn wait_for_completion_times_out() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        let mut body = response_json();
+        body["status"] = json!("queued");
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let error = client
+            .wait_for_completion(
+                "resp_123",
+                Duration::from_millis(5),
+                Some(Duration::from_millis(20)),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ClientError::Timeout { response_id } if response_id == "resp_123"));
+    }
+
+    #[tokio::test]
+    async fn cancel_posts_to_cancel_endpoint() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        let mut body = response_json();
+        body["status"] = json!("cancelled");
+        body["background"] = json!(true);
+        Mock::given(method("POST"))
+            .and(path("/responses/resp_123/cancel"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let response = client.cancel("resp_123").await.unwrap();
+        assert_eq!(response.status, types::ResponseStatus::Cancelled);
+        assert_eq!(response.background, Some(true));
+    }
+
+    #[tokio::test]
+    async fn create_sends_only_set_fields() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .and(body_json(json!({
+                "model": "gpt-4o",
+                "input": "Hello",
+                "stream": false,
+                "max_output_tokens": 64,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        client
+            .create(Request {
+                input: "Hello".into(),
+                max_output_tokens: Some(64),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn create_rejects_unsupported_strict_schemas_before_sending() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_json()))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let error = client
+            .create(Request {
+                tools: Some(vec![types::Tool::Function {
+                    name: "get_weather".to_string(),
+                    strict: true,
+                    description: None,
+                    parameters: json!({
+                        "type": "object",
+                        "properties": { "city": { "type": "string" } },
+                    }),
+                }]),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+
+        let ClientError::InvalidSchema(error) = error else {
+            panic!("expected an invalid schema error, got {error:?}");
+        };
+        assert_eq!(
+            error
+                .violations
+                .iter()
+                .map(|violation| violation.pointer.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "/tools/0/parameters/additionalProperties",
+                "/tools/0/parameters/properties/city",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn create_skips_schema_validation_when_disabled() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .validate_schemas(false)
+            .build()
+            .unwrap();
+
+        client
+            .create(Request {
+                tools: Some(vec![types::Tool::Function {
+                    name: "get_weather".to_string(),
+                    strict: true,
+                    description: None,
+                    parameters: json!({
+                        "type": "object",
+                        "properties": { "city": { "type": "string" } },
+                    }),
+                }]),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+    }
+
+    #[cfg(feature = "schemars")]
+    fn message_json(content: &serde_json::Value) -> serde_json::Value {
+        json!({
+            "type": "message",
+            "id": "msg_123",
+            "status": "completed",
+            "role": "assistant",
+            "content": [content],
+        })
+    }
+
+    #[cfg(feature = "schemars")]
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize, schemars::JsonSchema)]
+    /// A calendar event.
+    struct CalendarEvent {
+        name: String,
+        participants: Vec<String>,
+    }
+
+    #[cfg(feature = "schemars")]
+    #[tokio::test]
+    async fn create_parsed_sends_schema_and_parses_output() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let text = r#"{"name":"Science fair","participants":["Alice","Bob"]}"#;
+        let server = MockServer::start().await;
+        let mut response = response_json();
+        response["output"] = json!([message_json(&json!({
+            "type": "output_text",
+            "text": text,
+            "annotations": [],
+        }))]);
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let response = client
+            .create_parsed::<CalendarEvent>(Request {
+                input: "Alice and Bob are going to a science fair on Friday.".into(),
+                ..Default::default()
+            })
             .await
             .unwrap();
 
-        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            response.output_parsed,
+            Some(CalendarEvent {
+                name: "Science fair".to_string(),
+                participants: vec!["Alice".to_string(), "Bob".to_string()],
+            })
+        );
+        assert_eq!(response.output_text(), text);
+
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value = requests[0].body_json().unwrap();
+        assert_eq!(
+            body["text"]["format"],
+            json!({
+                "type": "json_schema",
+                "name": "CalendarEvent",
+                "description": "A calendar event.",
+                "strict": true,
+                "schema": {
+                    "title": "CalendarEvent",
+                    "description": "A calendar event.",
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "participants": { "type": "array", "items": { "type": "string" } },
+                    },
+                    "required": ["name", "participants"],
+                    "additionalProperties": false,
+                },
+            })
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[tokio::test]
+    async fn create_parsed_surfaces_refusals_and_invalid_output() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut response = response_json();
+        response["output"] = json!([message_json(&json!({
+            "type": "refusal",
+            "refusal": "I'm sorry, I can't help with that.",
+        }))]);
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let error = client
+            .create_parsed::<CalendarEvent>(Request::default())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, ClientError::Refusal { refusal } if refusal == "I'm sorry, I can't help with that.")
+        );
+
+        let server = MockServer::start().await;
+        let mut response = response_json();
+        response["output"] = json!([message_json(&json!({
+            "type": "output_text",
+            "text": r#"{"name":"Science fair""#,
+            "annotations": [],
+        }))]);
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let error = client
+            .create_parsed::<CalendarEvent>(Request::default())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, ClientError::InvalidOutput { text, .. } if text == r#"{"name":"Science fair""#)
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[tokio::test]
+    async fn create_parsed_allows_function_calls() {
+        use crate::types::Tool;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[derive(serde::Deserialize, schemars::JsonSchema)]
+        struct GetWeather {
+            location: String,
+        }
+
+        let server = MockServer::start().await;
+        let mut response = response_json();
+        response["output"] = json!([{
+            "type": "function_call",
+            "id": "fc_123",
+            "call_id": "call_123",
+            "name": "get_weather",
+            "arguments": r#"{"location":"Paris, France"}"#,
+            "status": "completed",
+        }]);
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let response = client
+            .create_parsed::<CalendarEvent>(Request {
+                tools: Some(vec![Tool::function::<GetWeather>(
+                    "get_weather",
+                    "Get the current weather for a location.",
+                )]),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert!(response.output_parsed.is_none());
+        let call = response.function_calls().next().unwrap();
+        assert_eq!(
+            call.parse_arguments::<GetWeather>().unwrap().location,
+            "Paris, France"
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[tokio::test]
+    async fn create_parsed_allows_other_tool_calls() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[allow(dead_code)]
+        #[derive(serde::Deserialize, schemars::JsonSchema)]
+        struct Query {
+            sql: String,
+        }
+
+        let server = MockServer::start().await;
+        let mut response = response_json();
+        response["output"] = json!([{
+            "type": "custom_tool_call",
+            "id": "ctc_123",
+            "call_id": "call_123",
+            "name": "run_sql",
+            "input": "SELECT name FROM users;",
+        }]);
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let response = client
+            .create_parsed::<Query>(Request::default())
+            .await
+            .unwrap();
+        assert!(response.output_parsed.is_none());
+
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value = requests[0].body_json().unwrap();
+        assert!(body["text"]["format"].get("description").is_none());
+    }
+
+    #[tokio::test]
+    async fn get_encodes_include_as_array() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response_json()))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        client.get("resp_123", None).await.unwrap();
+        client
+            .get(
+                "resp_123",
+                Some(vec![Include::FileSearchResults, Include::InputImageURLs]),
+            )
+            .await
+            .unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests[0].url.query(), None);
+        assert_eq!(
+            requests[1].url.query(),
+            Some(
+                "include%5B%5D=file_search_call.results&include%5B%5D=message.input_image.image_url"
+            )
+        );
+    }
+
+    fn input_item_json(id: &str) -> serde_json::Value {
+        json!({ "type": "function_call_output", "id": id, "call_id": "call_123", "output": "{}" })
+    }
+
+    #[tokio::test]
+    async fn list_inputs_sends_pagination_params() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123/inputs"))
+            .and(query_param("after", "item_1"))
+            .and(query_param("include[]", "file_search_call.results"))
+            .and(query_param("limit", "2"))
+            .and(query_param("order", "asc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "data": [input_item_json("item_2")],
+                "first_id": "item_2",
+                "last_id": "item_2",
+                "has_more": false,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let params = InputItemListParams {
+            after: Some("item_1".to_string()),
+            include: Some(vec![Include::FileSearchResults]),
+            limit: Some(2),
+            order: Some(types::Order::Asc),
+            ..Default::default()
+        };
+        let list = client.list_inputs("resp_123", &params).await.unwrap();
+        assert_eq!(list.data.len(), 1);
+        assert_eq!(list.last_id.as_deref(), Some("item_2"));
+        assert!(!list.has_more);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn list_all_inputs_follows_cursor() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123/inputs"))
+            .and(query_param_is_missing("after"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "data": [input_item_json("item_1"), input_item_json("item_2")],
+                "first_id": "item_1",
+                "last_id": "item_2",
+                "has_more": true,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123/inputs"))
+            .and(query_param("after", "item_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "data": [input_item_json("item_3")],
+                "first_id": "item_3",
+                "last_id": "item_3",
+                "has_more": false,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let ids = client
+            .list_all_inputs("resp_123", InputItemListParams::default())
+            .map(|item| match item.unwrap() {
+                types::InputItem::FunctionCallOutput(output) => output.id.unwrap(),
+                item => panic!("unexpected item: {item:?}"),
+            })
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(ids, ["item_1", "item_2", "item_3"]);
+    }
+
+    #[cfg(feature = "stream")]
+    fn sse(events: &[serde_json::Value]) -> String {
+        use std::fmt::Write;
+
+        events.iter().fold(String::new(), |mut body, event| {
+            let _ = write!(body, "data: {event}\n\n");
+            body
+        })
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn resume_stream_requests_events_after_sequence_number() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        let body = sse(&[
+            json!({ "type": "response.completed", "response": response_json(), "sequence_number": 8 }),
+        ]);
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .and(query_param("stream", "true"))
+            .and(query_param("starting_after", "7"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        let events = client
+            .resume_stream("resp_123", Some(7))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_ref().unwrap().sequence_number(), Some(8));
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_resumes_after_dropped_connection() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        let mut queued = response_json();
+        queued["status"] = json!("queued");
+        let delta = |sequence_number: u64| {
+            json!({
+                "type": "response.output_text.delta",
+                "item_id": "msg_123",
+                "output_index": 0,
+                "content_index": 0,
+                "delta": "Hi",
+                "sequence_number": sequence_number
+            })
+        };
+
+        // The first connection ends before the response completes.
+        let body = sse(&[
+            json!({ "type": "response.created", "response": queued, "sequence_number": 0 }),
+            delta(1),
+        ]);
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let body = sse(&[
+            delta(2),
+            json!({ "type": "response.completed", "response": response_json(), "sequence_number": 3 }),
+        ]);
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .and(query_param("starting_after", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .reconnect_policy(ReconnectPolicy::Resume { max_attempts: 1 })
+            .build()
+            .unwrap();
+
+        let sequence_numbers = client
+            .stream(Request::default())
+            .map(|event| event.unwrap().sequence_number())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(sequence_numbers, [Some(0), Some(1), Some(2), Some(3)]);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_counts_failed_reconnects_as_attempts() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        let mut queued = response_json();
+        queued["status"] = json!("queued");
+        let body =
+            sse(&[json!({ "type": "response.created", "response": queued, "sequence_number": 0 })]);
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // The first reconnect fails, the second one succeeds.
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        let body = sse(&[
+            json!({ "type": "response.completed", "response": response_json(), "sequence_number": 1 }),
+        ]);
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries().max_retries(0))
+            .reconnect_policy(ReconnectPolicy::Resume { max_attempts: 2 })
+            .build()
+            .unwrap();
+
+        let sequence_numbers = client
+            .stream(Request::default())
+            .map(|event| event.unwrap().sequence_number())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(sequence_numbers, [Some(0), Some(1)]);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_ends_when_reconnect_is_rejected() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        let mut queued = response_json();
+        queued["status"] = json!("queued");
+        let body =
+            sse(&[json!({ "type": "response.created", "response": queued, "sequence_number": 0 })]);
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "error": { "message": "Response is not a background response.", "type": "invalid_request_error", "param": null, "code": null },
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries().max_retries(0))
+            .reconnect_policy(ReconnectPolicy::Resume { max_attempts: 3 })
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+        assert_eq!(events.len(), 2);
+        assert!(events[0].is_ok());
+        assert_eq!(
+            events[1].as_ref().unwrap_err().status(),
+            Some(StatusCode::BAD_REQUEST)
+        );
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_never_resends_request_after_dropped_connection() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let created = json!({ "type": "response.created", "response": response_json(), "sequence_number": 0 });
+        // The mock server expects exactly one request, so a re-sent request fails verification.
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(sse(&[created]), "text/event-stream"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .reconnect_policy(ReconnectPolicy::Never)
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], Ok(Event::ResponseCreated { .. })));
+        assert!(matches!(events[1], Err(StreamError::ConnectionClosed)));
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_ends_on_error_responses_without_resending() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .reconnect_policy(ReconnectPolicy::Bounded { max_attempts: 3 })
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            Err(StreamError::Api(error)) if error.status == StatusCode::UNAUTHORIZED
+        ));
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_surfaces_error_body_when_opening_fails() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .insert_header("x-request-id", "req_abc")
+                    .set_body_json(json!({
+                        "error": {
+                            "type": "invalid_request_error",
+                            "message": "Your input exceeds the context window of this model.",
+                            "code": "context_length_exceeded",
+                            "param": "input"
+                        }
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .reconnect_policy(ReconnectPolicy::Never)
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+
+        let [Err(error)] = &events[..] else {
+            panic!("expected a single error, got {events:?}");
+        };
+        assert_eq!(error.status(), Some(StatusCode::BAD_REQUEST));
+        assert_eq!(error.request_id(), Some("req_abc"));
+        assert_eq!(
+            error.api_error().unwrap().code.as_deref(),
+            Some("context_length_exceeded")
+        );
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_accepts_event_streams_without_event_stream_content_type() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let completed = json!({ "type": "response.completed", "response": response_json(), "sequence_number": 0 });
+        // Some proxies rewrite the content type of event streams.
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(sse(&[completed]), "application/octet-stream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .reconnect_policy(ReconnectPolicy::Never)
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+
+        assert!(matches!(&events[..], [Ok(Event::ResponseCompleted { .. })]));
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_continues_after_malformed_event() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let completed = json!({ "type": "response.completed", "response": response_json(), "sequence_number": 1 });
+        let body = format!("data: {{not json}}\n\n{}", sse(&[completed]));
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .reconnect_policy(ReconnectPolicy::Never)
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], Err(StreamError::Parsing(_))));
+        assert!(matches!(events[1], Ok(Event::ResponseCompleted { .. })));
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_yields_unknown_events() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let unknown =
+            json!({ "type": "response.brand_new.delta", "delta": "x", "sequence_number": 1 });
+        let completed = json!({ "type": "response.completed", "response": response_json(), "sequence_number": 2 });
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(sse(&[unknown.clone(), completed]), "text/event-stream"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .reconnect_policy(ReconnectPolicy::Never)
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+
+        assert_eq!(events.len(), 2);
+        let event = events[0].as_ref().unwrap();
+        assert!(
+            matches!(event, Event::Unknown { r#type, .. } if r#type == "response.brand_new.delta")
+        );
+        assert_eq!(event.sequence_number(), Some(1));
+        assert_eq!(serde_json::to_value(event).unwrap(), unknown);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn bounded_policy_resends_only_before_first_event() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let created = json!({ "type": "response.created", "response": response_json(), "sequence_number": 0 });
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(String::new(), "text/event-stream"),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(sse(&[created]), "text/event-stream"),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .reconnect_policy(ReconnectPolicy::Bounded { max_attempts: 3 })
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+
+        // The first, empty stream is re-sent; the second drops after an event, which ends the stream.
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], Ok(Event::ResponseCreated { .. })));
+        assert!(matches!(events[1], Err(StreamError::ConnectionClosed)));
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+    }
+
+    fn fast_retries() -> RetryPolicy {
+        RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(1))
+            .max_backoff(Duration::from_millis(5))
+    }
+
+    #[tokio::test]
+    async fn retries_transient_failures() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(503).insert_header("retry-after-ms", "1"))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/responses/resp_123"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .build()
+            .unwrap();
+
+        client.delete("resp_123").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors_or_past_max_retries() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/responses/resp_400"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/responses/resp_500"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(4)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries().max_retries(3))
+            .build()
+            .unwrap();
+
+        assert!(client.delete("resp_400").await.is_err());
+        assert!(client.delete("resp_500").await.is_err());
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn stream_retries_failed_connection() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                "data: {\"type\":\"error\",\"code\":null,\"message\":\"boom\",\"param\":null}\n\n",
+                "text/event-stream",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("sk-test")
+            .base_url(server.uri())
+            .retry_policy(fast_retries())
+            .build()
+            .unwrap();
+
+        let events = client.stream(Request::default()).collect::<Vec<_>>().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], Ok(Event::Error { message, .. }) if message == "boom"));
+    }
+
+    #[test]
+    fn azure_uses_resource_endpoint() {
+        let client = Client::builder()
+            .api_key("azure-key")
+            .azure(AzureConfig::new("https://my-resource.openai.azure.com/"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            client.base_url(),
+            "https://my-resource.openai.azure.com/openai"
+        );
+    }
+
+    #[tokio::test]
+    async fn azure_rewrites_auth_query_and_model() {
+        use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/openai/responses"))
+            .and(header("api-key", "azure-key"))
+            .and(query_param("api-version", "2025-03-01-preview"))
+            .and(body_partial_json(json!({ "model": "my-deployment" })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("azure-key")
+            .azure(
+                AzureConfig::new(server.uri())
+                    .api_version("2025-03-01-preview")
+                    .deployment("my-deployment"),
+            )
+            .build()
+            .unwrap();
+
+        let (status, _) = client.create_raw(Request::default()).await.unwrap();
+        assert_eq!(status, StatusCode::OK);
+
+        let requests = server.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key(header::AUTHORIZATION));
     }
 }
@@ -0,0 +1,143 @@
+use reqwest::{StatusCode, header::HeaderMap};
+use std::time::Duration;
+
+/// Metadata about an API response, read from its status and headers.
+#[derive(Debug, Clone)]
+pub struct ResponseMetadata {
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+    /// The `x-request-id` header, useful when contacting support.
+    pub request_id: Option<String>,
+    /// How long the API took to process the request, from the `openai-processing-ms` header.
+    pub processing_time: Option<Duration>,
+    /// The rate limit state reported by the `x-ratelimit-*` headers.
+    pub rate_limits: RateLimits,
+    /// All headers returned with the response.
+    pub headers: HeaderMap,
+}
+
+impl ResponseMetadata {
+    pub(crate) fn from_response(response: &reqwest::Response) -> Self {
+        let headers = response.headers();
+
+        Self {
+            status: response.status(),
+            rate_limits: RateLimits::from_headers(headers),
+            request_id: header_str(headers, "x-request-id").map(ToString::to_string),
+            processing_time: header_str(headers, "openai-processing-ms")
+                .and_then(|ms| ms.parse::<f64>().ok())
+                .and_then(|ms| Duration::try_from_secs_f64(ms / 1000.0).ok()),
+            headers: headers.clone(),
+        }
+    }
+}
+
+/// The rate limit state reported by the `x-ratelimit-*` headers.
+///
+/// See the [rate limits guide](https://platform.openai.com/docs/guides/rate-limits#rate-limits-in-headers) for more information.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimits {
+    /// The maximum number of requests permitted before exhausting the rate limit.
+    pub limit_requests: Option<u64>,
+    /// The maximum number of tokens permitted before exhausting the rate limit.
+    pub limit_tokens: Option<u64>,
+    /// The remaining number of requests permitted before exhausting the rate limit.
+    pub remaining_requests: Option<u64>,
+    /// The remaining number of tokens permitted before exhausting the rate limit.
+    pub remaining_tokens: Option<u64>,
+    /// The time until the request rate limit resets to its initial state.
+    pub reset_requests: Option<Duration>,
+    /// The time until the token rate limit resets to its initial state.
+    pub reset_tokens: Option<Duration>,
+}
+
+impl RateLimits {
+    /// Reads the rate limit state from the `x-ratelimit-*` headers of a response.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            limit_requests: header_str(headers, "x-ratelimit-limit-requests")
+                .and_then(|v| v.parse().ok()),
+            limit_tokens: header_str(headers, "x-ratelimit-limit-tokens")
+                .and_then(|v| v.parse().ok()),
+            remaining_requests: header_str(headers, "x-ratelimit-remaining-requests")
+                .and_then(|v| v.parse().ok()),
+            remaining_tokens: header_str(headers, "x-ratelimit-remaining-tokens")
+                .and_then(|v| v.parse().ok()),
+            reset_requests: header_str(headers, "x-ratelimit-reset-requests").and_then(parse_reset),
+            reset_tokens: header_str(headers, "x-ratelimit-reset-tokens").and_then(parse_reset),
+        }
+    }
+}
+
+pub fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+/// Parses rate limit reset durations such as `20ms`, `1s` or `6m0.5s`.
+fn parse_reset(value: &str) -> Option<Duration> {
+    let mut total = Duration::ZERO;
+    let mut rest = value.trim();
+
+    while !rest.is_empty() {
+        let unit_start = rest.find(|c: char| c.is_ascii_alphabetic())?;
+        let number = rest[..unit_start].parse::<f64>().ok()?;
+        let unit_len = rest[unit_start..]
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len() - unit_start);
+
+        let seconds = match &rest[unit_start..unit_start + unit_len] {
+            "ms" => number / 1000.0,
+            "s" => number,
+            "m" => number * 60.0,
+            "h" => number * 3600.0,
+            _ => return None,
+        };
+
+        total += Duration::try_from_secs_f64(seconds).ok()?;
+        rest = &rest[unit_start + unit_len..];
+    }
+
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn parses_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        for (name, value) in [
+            ("x-ratelimit-limit-requests", "500"),
+            ("x-ratelimit-limit-tokens", "30000"),
+            ("x-ratelimit-remaining-requests", "499"),
+            ("x-ratelimit-remaining-tokens", "29950"),
+            ("x-ratelimit-reset-requests", "120ms"),
+            ("x-ratelimit-reset-tokens", "6m0.5s"),
+        ] {
+            headers.insert(name, HeaderValue::from_static(value));
+        }
+
+        assert_eq!(
+            RateLimits::from_headers(&headers),
+            RateLimits {
+                limit_requests: Some(500),
+                limit_tokens: Some(30000),
+                remaining_requests: Some(499),
+                remaining_tokens: Some(29950),
+                reset_requests: Some(Duration::from_millis(120)),
+                reset_tokens: Some(Duration::from_millis(360_500)),
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_missing_or_malformed_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-reset-tokens", HeaderValue::from_static("soon"));
+
+        assert_eq!(RateLimits::from_headers(&headers), RateLimits::default());
+    }
+}
@@ -0,0 +1,85 @@
+use serde::de::DeserializeOwned;
+use std::ops::Deref;
+
+use crate::{
+    ClientError,
+    types::{OutputContent, OutputItem, Response},
+};
+
+/// A model response whose output was parsed into `T`. Returned by [`Client::create_parsed`](crate::Client::create_parsed).
+///
+/// Dereferences to the underlying [`Response`].
+#[derive(Debug, Clone)]
+pub struct ParsedResponse<T> {
+    /// The output of the model, parsed into `T`. `None` if the model called tools instead of responding.
+    pub output_parsed: Option<T>,
+    /// The response the output was parsed from.
+    pub response: Response,
+}
+
+impl<T: DeserializeOwned> ParsedResponse<T> {
+    /// Parses the text output of `response`, failing if the model refused to respond.
+    pub(crate) fn new(response: Response) -> Result<Self, ClientError> {
+        let mut text = String::new();
+        let contents = response
+            .output
+            .iter()
+            .filter_map(|output| match output {
+                OutputItem::Message(message) => Some(message),
+                _ => None,
+            })
+            .flat_map(|message| &message.content);
+
+        for content in contents {
+            match content {
+                OutputContent::Text { text: part, .. } => text.push_str(part),
+                OutputContent::Refusal { refusal } => {
+                    return Err(ClientError::Refusal {
+                        refusal: refusal.clone(),
+                    });
+                }
+                OutputContent::Unknown { .. } => {}
+            }
+        }
+
+        if text.is_empty() && response.output.iter().any(is_tool_call) {
+            return Ok(Self {
+                response,
+                output_parsed: None,
+            });
+        }
+
+        let output_parsed = serde_json::from_str(&text)
+            .map_err(|error| ClientError::InvalidOutput { error, text })?;
+
+        Ok(Self {
+            response,
+            output_parsed: Some(output_parsed),
+        })
+    }
+}
+
+/// Whether `item` is a call the model made to a tool, whether run by the API or by the caller.
+const fn is_tool_call(item: &OutputItem) -> bool {
+    matches!(
+        item,
+        OutputItem::FileSearch(_)
+            | OutputItem::FunctionCall(_)
+            | OutputItem::WebSearchResults(_)
+            | OutputItem::ComputerToolCall(_)
+            | OutputItem::ImageGeneration(_)
+            | OutputItem::CustomToolCall(_)
+            | OutputItem::CodeInterpreterCall(_)
+            | OutputItem::LocalShellCall(_)
+            | OutputItem::McpCall(_)
+            | OutputItem::McpApprovalRequest(_)
+    )
+}
+
+impl<T> Deref for ParsedResponse<T> {
+    type Target = Response;
+
+    fn deref(&self) -> &Self::Target {
+        &self.response
+    }
+}
@@ -0,0 +1,567 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Number, Value};
+use std::{iter::Peekable, str::Chars};
+#[cfg(feature = "stream")]
+use {
+    crate::types::Event,
+    async_fn_stream::fn_stream,
+    futures::{Stream, StreamExt},
+};
+
+/// The structured output of a streaming response, parsed from the text received so far.
+#[derive(Debug, Clone)]
+pub struct Partial<T> {
+    /// The ID of the output message the text belongs to.
+    pub item_id: String,
+    /// The text received so far.
+    pub text: String,
+    /// The text parsed as JSON, with open strings, arrays and objects closed. See [`parse_partial_json`].
+    pub value: Option<Value>,
+    /// The value deserialized into `T`, if it matches.
+    ///
+    /// Incomplete objects are missing their later fields, so give `T` optional or `#[serde(default)]` fields to
+    /// have it match early in the stream.
+    pub parsed: Option<T>,
+    /// Whether the text is complete.
+    pub done: bool,
+}
+
+impl<T: DeserializeOwned> Partial<T> {
+    /// Parses the text of an output message, which may be cut off at any point unless `done` is set.
+    #[must_use]
+    pub fn new(item_id: impl Into<String>, text: impl Into<String>, done: bool) -> Self {
+        let text = text.into();
+        let value = if done {
+            serde_json::from_str(&text).ok()
+        } else {
+            parse_partial_json(&text)
+        };
+
+        Self {
+            parsed: value.as_ref().and_then(|value| T::deserialize(value).ok()),
+            value,
+            done,
+            text,
+            item_id: item_id.into(),
+        }
+    }
+}
+
+/// Parses JSON that may be cut off at any point, such as the output text of a response that is still streaming.
+///
+/// Open strings, arrays and objects are closed, and partial `true`, `false` and `null` literals completed.
+/// Numbers cut off after a `-`, `.` or exponent marker are shortened to the digits before it, e.g. `12.` to `12`, and object
+/// keys without a value, or whose number has no digits yet, are left out until more text arrives.
+///
+/// Returns `None` if the text doesn't contain a value yet, isn't the beginning of a valid JSON document, or nests
+/// arrays and objects more than 128 levels deep.
+///
+/// # Examples
+/// ```rust
+/// use openai_responses::parse_partial_json;
+/// use serde_json::json;
+///
+/// assert_eq!(
+///     parse_partial_json(r#"{"title": "Team sync", "attendees": ["Ali", "Sa"#),
+///     Some(json!({ "title": "Team sync", "attendees": ["Ali", "Sa"] }))
+/// );
+/// ```
+#[must_use]
+pub fn parse_partial_json(text: &str) -> Option<Value> {
+    let mut parser = Parser {
+        chars: text.chars().peekable(),
+        depth: 0,
+    };
+
+    let value = parser.value().ok()?;
+    parser.skip_whitespace();
+    if parser.chars.peek().is_some() {
+        return None;
+    }
+
+    value
+}
+
+#[cfg(feature = "stream")]
+/// Turns a stream of events into the structured output parsed so far, updated after every text delta.
+///
+/// Yields a [`Partial`] for every [`Event::OutputTextDelta`], and a final one with `done` set for every
+/// [`Event::OutputTextDone`]. Other events are skipped, and errors are passed through.
+///
+/// # Examples
+/// ```rust no_run
+/// # async fn run(client: openai_responses::Client, request: openai_responses::types::Request) {
+/// use futures::StreamExt;
+/// use openai_responses::{Partial, partial_outputs};
+///
+/// #[derive(Debug, serde::Deserialize)]
+/// struct CalendarEvent {
+///     #[serde(default)]
+///     name: String,
+///     #[serde(default)]
+///     participants: Vec<String>,
+/// }
+///
+/// let mut outputs = std::pin::pin!(partial_outputs::<CalendarEvent, _, _>(client.stream(request)));
+/// while let Some(Ok(Partial { parsed: Some(event), .. })) = outputs.next().await {
+///     println!("{event:?}");
+/// }
+/// # }
+/// ```
+pub fn partial_outputs<T, E, S>(events: S) -> impl Stream<Item = Result<Partial<T>, E>>
+where
+    T: DeserializeOwned,
+    S: Stream<Item = Result<Event, E>>,
+{
+    fn_stream(|emitter| async move {
+        let mut events = std::pin::pin!(events);
+        let mut current: Option<(String, u64)> = None;
+        let mut text = String::new();
+
+        while let Some(event) = events.next().await {
+            let partial = match event {
+                Ok(Event::OutputTextDelta {
+                    item_id,
+                    content_index,
+                    delta,
+                    ..
+                }) => {
+                    let part = Some((item_id.clone(), content_index));
+                    if current != part {
+                        current = part;
+                        text.clear();
+                    }
+                    text.push_str(&delta);
+
+                    Ok(Partial::new(item_id, text.clone(), false))
+                }
+                Ok(Event::OutputTextDone {
+                    item_id,
+                    text: done_text,
+                    ..
+                }) => {
+                    current = None;
+                    text.clear();
+
+                    Ok(Partial::new(item_id, done_text, true))
+                }
+                Ok(_) => continue,
+                Err(error) => Err(error),
+            };
+
+            emitter.emit(partial).await;
+        }
+    })
+}
+
+/// The text is not the beginning of a valid JSON document.
+struct Invalid;
+
+/// How deeply arrays and objects may nest, matching `serde_json`, so deeply nested text can't overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// A recursive descent JSON parser that treats the end of the text as the end of every open value.
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    /// The number of arrays and objects currently open.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .next_if(|c| matches!(c, ' ' | '\t' | '\n' | '\r'))
+            .is_some()
+        {}
+    }
+
+    /// Parses the next value, returning `None` if the text ends before any of it can be used.
+    fn value(&mut self) -> Result<Option<Value>, Invalid> {
+        self.skip_whitespace();
+
+        match self.chars.peek() {
+            None => Ok(None),
+            Some(&c @ ('{' | '[')) => {
+                if self.depth == MAX_DEPTH {
+                    return Err(Invalid);
+                }
+
+                self.depth += 1;
+                let value = if c == '{' {
+                    self.object()
+                } else {
+                    self.array()
+                };
+                self.depth -= 1;
+
+                value.map(Some)
+            }
+            Some('"') => Ok(Some(Value::String(self.string()?.0))),
+            Some('-' | '0'..='9') => self.number(),
+            Some(_) => self.literal(),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, Invalid> {
+        self.chars.next();
+        let mut object = Map::new();
+
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                None => return Ok(Value::Object(object)),
+                Some('}') if object.is_empty() => {
+                    self.chars.next();
+                    return Ok(Value::Object(object));
+                }
+                Some('"') => {}
+                Some(_) => return Err(Invalid),
+            }
+
+            let (key, complete) = self.string()?;
+            if !complete {
+                return Ok(Value::Object(object));
+            }
+
+            self.skip_whitespace();
+            match self.chars.next() {
+                None => return Ok(Value::Object(object)),
+                Some(':') => {}
+                Some(_) => return Err(Invalid),
+            }
+
+            let Some(value) = self.value()? else {
+                return Ok(Value::Object(object));
+            };
+            object.insert(key, value);
+
+            self.skip_whitespace();
+            match self.chars.next() {
+                None | Some('}') => return Ok(Value::Object(object)),
+                Some(',') => {}
+                Some(_) => return Err(Invalid),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, Invalid> {
+        self.chars.next();
+        let mut array = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(Value::Array(array));
+        }
+
+        loop {
+            let Some(value) = self.value()? else {
+                return Ok(Value::Array(array));
+            };
+            array.push(value);
+
+            self.skip_whitespace();
+            match self.chars.next() {
+                None | Some(']') => return Ok(Value::Array(array)),
+                Some(',') => {}
+                Some(_) => return Err(Invalid),
+            }
+        }
+    }
+
+    /// Parses a string, returning whether its closing quote was reached.
+    fn string(&mut self) -> Result<(String, bool), Invalid> {
+        self.chars.next();
+        let mut string = String::new();
+
+        loop {
+            match self.chars.next() {
+                None => return Ok((string, false)),
+                Some('"') => return Ok((string, true)),
+                Some('\\') => match self.escape()? {
+                    Some(c) => string.push(c),
+                    None => return Ok((string, false)),
+                },
+                Some(c) if c < ' ' => return Err(Invalid),
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    /// Parses the rest of an escape sequence, returning `None` if the text ends before it does.
+    fn escape(&mut self) -> Result<Option<char>, Invalid> {
+        let Some(c) = self.chars.next() else {
+            return Ok(None);
+        };
+
+        let c = match c {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let Some(high) = self.hex()? else {
+                    return Ok(None);
+                };
+                if !(0xD800..0xDC00).contains(&high) {
+                    return char::from_u32(u32::from(high)).map(Some).ok_or(Invalid);
+                }
+
+                // A high surrogate must be followed by an escaped low surrogate.
+                match (self.chars.next(), self.chars.next()) {
+                    (None, _) | (Some('\\'), None) => return Ok(None),
+                    (Some('\\'), Some('u')) => {}
+                    _ => return Err(Invalid),
+                }
+                let Some(low) = self.hex()? else {
+                    return Ok(None);
+                };
+
+                return char::decode_utf16([high, low])
+                    .next()
+                    .and_then(Result::ok)
+                    .map(Some)
+                    .ok_or(Invalid);
+            }
+            _ => return Err(Invalid),
+        };
+
+        Ok(Some(c))
+    }
+
+    /// Parses the four hex digits of a `\u` escape, returning `None` if the text ends before them.
+    fn hex(&mut self) -> Result<Option<u16>, Invalid> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let Some(c) = self.chars.next() else {
+                return Ok(None);
+            };
+            let digit = c.to_digit(16).ok_or(Invalid)?;
+            code = code * 16 + u16::try_from(digit).map_err(|_| Invalid)?;
+        }
+
+        Ok(Some(code))
+    }
+
+    fn number(&mut self) -> Result<Option<Value>, Invalid> {
+        let mut number = String::new();
+        while let Some(c) = self
+            .chars
+            .next_if(|c| matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+        {
+            number.push(c);
+        }
+
+        // The number may continue in the next delta, so only use its complete prefix.
+        if self.chars.peek().is_none() {
+            let complete = number.trim_end_matches(['-', '+', '.', 'e', 'E']);
+            return Ok(complete.parse::<Number>().ok().map(Value::Number));
+        }
+
+        number
+            .parse::<Number>()
+            .map(|number| Some(Value::Number(number)))
+            .map_err(|_| Invalid)
+    }
+
+    fn literal(&mut self) -> Result<Option<Value>, Invalid> {
+        let mut literal = String::new();
+        while let Some(c) = self.chars.next_if(char::is_ascii_alphabetic) {
+            literal.push(c);
+        }
+
+        let value = [
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+            ("null", Value::Null),
+        ]
+        .into_iter()
+        .find(|(name, _)| {
+            *name == literal || (self.chars.peek().is_none() && name.starts_with(&literal))
+        });
+
+        value.map(|(_, value)| Some(value)).ok_or(Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_every_prefix_of_a_document() {
+        let document = json!({
+            "name": "Science fair \"prep\" \u{1F9EA}",
+            "day": -12.5e1,
+            "confirmed": true,
+            "notes": null,
+            "participants": ["Alice", "Bob"],
+            "location": { "room": "B/12", "floor": 3 },
+            "tags": [],
+        });
+        let text = serde_json::to_string_pretty(&document)
+            .unwrap()
+            .replace('\u{1F9EA}', "\\ud83e\\uddea");
+
+        for (end, _) in text.char_indices() {
+            assert!(
+                parse_partial_json(&text[..end]).is_some() || text[..end].trim().is_empty(),
+                "failed to parse {:?}",
+                &text[..end]
+            );
+        }
+        assert_eq!(parse_partial_json(&text), Some(document));
+    }
+
+    #[test]
+    fn closes_open_values() {
+        for (text, expected) in [
+            ("", None),
+            ("  ", None),
+            ("{", Some(json!({}))),
+            (r#"{"na"#, Some(json!({}))),
+            (r#"{"name""#, Some(json!({}))),
+            (r#"{"name": "Sci"#, Some(json!({ "name": "Sci" }))),
+            (r#"{"name": "a\"#, Some(json!({ "name": "a" }))),
+            (r#"{"name": "a\u00"#, Some(json!({ "name": "a" }))),
+            (r#"{"name": "a\ud83e"#, Some(json!({ "name": "a" }))),
+            (r#"{"name": "a\n"#, Some(json!({ "name": "a\n" }))),
+            (r#"{"day": -"#, Some(json!({}))),
+            (r#"{"day": 12."#, Some(json!({ "day": 12 }))),
+            (r#"{"day": 1.5e"#, Some(json!({ "day": 1.5 }))),
+            (r#"{"day": 2e-"#, Some(json!({ "day": 2 }))),
+            ("[-", Some(json!([]))),
+            (r#"{"ok": tr"#, Some(json!({ "ok": true }))),
+            (r#"{"ok": n"#, Some(json!({ "ok": null }))),
+            (r#"{"list": [1, 2"#, Some(json!({ "list": [1, 2] }))),
+            (r#"{"list": [1, "#, Some(json!({ "list": [1] }))),
+            (
+                r#"{"list": [{"a": [{"b": "c"#,
+                Some(json!({ "list": [{ "a": [{ "b": "c" }] }] })),
+            ),
+            (r#"["a", {"#, Some(json!(["a", {}]))),
+            (r#""text"#, Some(json!("text"))),
+        ] {
+            assert_eq!(parse_partial_json(text), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        for text in [
+            "{]",
+            r#"{"a" 1"#,
+            r#"{"a": tru }"#,
+            r#"{"a": yes"#,
+            r#"{"a": 1} x"#,
+            "[1 2]",
+            r#"{"a": "\x"#,
+            "[1,]",
+            r#"{"a": 1,}"#,
+            r#"{"a": 1.2.3 }"#,
+        ] {
+            assert_eq!(parse_partial_json(text), None, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn limits_nesting_depth() {
+        assert_eq!(parse_partial_json(&"[".repeat(200_000)), None);
+        assert_eq!(parse_partial_json(&r#"{"a":"#.repeat(200_000)), None);
+
+        let nested = "[".repeat(MAX_DEPTH);
+        let mut expected = json!([]);
+        for _ in 1..MAX_DEPTH {
+            expected = json!([expected]);
+        }
+        assert_eq!(parse_partial_json(&nested), Some(expected));
+        assert_eq!(parse_partial_json(&format!("{nested}[")), None);
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct CalendarEvent {
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        participants: Vec<String>,
+    }
+
+    #[test]
+    fn partial_deserializes_what_matches() {
+        let partial = Partial::<CalendarEvent>::new("msg_1", r#"{"name": "Sync", "partic"#, false);
+        assert_eq!(
+            partial.parsed,
+            Some(CalendarEvent {
+                name: "Sync".to_string(),
+                participants: vec![],
+            })
+        );
+
+        let partial = Partial::<CalendarEvent>::new("msg_1", r#"{"name": 4"#, false);
+        assert_eq!(partial.value, Some(json!({ "name": 4 })));
+        assert_eq!(partial.parsed, None);
+
+        let partial = Partial::<CalendarEvent>::new("msg_1", r#"{"name": "Sync""#, true);
+        assert_eq!(partial.value, None);
+        assert_eq!(partial.parsed, None);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn partial_outputs_updates_after_every_delta() {
+        let text = r#"{"name": "Science fair", "participants": ["Alice", "Bob"]}"#;
+        let (first, second) = text.split_at(44);
+        let event = |event: Value| serde_json::from_value::<Event>(event).map_err(|_| ());
+
+        let events = futures::stream::iter([
+            event(
+                json!({ "type": "response.output_text.delta", "item_id": "msg_1", "output_index": 0, "content_index": 0, "delta": first }),
+            ),
+            event(
+                json!({ "type": "response.output_text.delta", "item_id": "msg_1", "output_index": 0, "content_index": 0, "delta": second }),
+            ),
+            Err(()),
+            event(
+                json!({ "type": "response.output_text.done", "item_id": "msg_1", "output_index": 0, "content_index": 0, "text": text }),
+            ),
+            event(
+                json!({ "type": "response.output_text.delta", "item_id": "msg_2", "output_index": 1, "content_index": 0, "delta": "{\"na" }),
+            ),
+        ]);
+
+        let partials = partial_outputs::<CalendarEvent, _, _>(events)
+            .collect::<Vec<_>>()
+            .await;
+        let partials = partials
+            .into_iter()
+            .map(|partial| partial.map(|partial| (partial.item_id, partial.parsed, partial.done)))
+            .collect::<Vec<_>>();
+
+        let event = |participants: &[&str]| CalendarEvent {
+            name: "Science fair".to_string(),
+            participants: participants.iter().map(ToString::to_string).collect(),
+        };
+        assert_eq!(
+            partials,
+            [
+                Ok(("msg_1".to_string(), Some(event(&["A"])), false)),
+                Ok(("msg_1".to_string(), Some(event(&["Alice", "Bob"])), false)),
+                Err(()),
+                Ok(("msg_1".to_string(), Some(event(&["Alice", "Bob"])), true)),
+                Ok((
+                    "msg_2".to_string(),
+                    Some(CalendarEvent {
+                        name: String::new(),
+                        participants: vec![],
+                    }),
+                    false
+                )),
+            ]
+        );
+    }
+}
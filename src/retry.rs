@@ -0,0 +1,238 @@
+use reqwest::{StatusCode, header::HeaderMap};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// The longest `Retry-After` delay the client will honor. Longer delays fall back to exponential backoff.
+const MAX_RETRY_AFTER: Duration = Duration::from_mins(1);
+
+/// Controls how the [`Client`](crate::Client) retries failed requests.
+///
+/// Connection errors, timeouts and `408`, `409`, `429` and `5xx` responses are retried with exponential backoff and jitter,
+/// honoring the `retry-after-ms` and `Retry-After` headers when the server sends them.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use openai_responses::{Client, RetryPolicy};
+///
+/// let client = Client::builder()
+///     .api_key("sk-my-api-key")
+///     .retry_policy(RetryPolicy::default().max_retries(5).max_backoff(Duration::from_secs(30)))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Retries up to 2 times, backing off from 0.5 seconds up to 8 seconds.
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            max_backoff: Duration::from_secs(8),
+            initial_backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    #[must_use]
+    pub fn never() -> Self {
+        Self::default().max_retries(0)
+    }
+
+    /// Sets the maximum number of retries after the initial attempt.
+    #[must_use]
+    pub const fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry. Each subsequent retry doubles it.
+    #[must_use]
+    pub const fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound for the delay between retries.
+    #[must_use]
+    pub const fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Whether another attempt is allowed after `attempt` retries.
+    pub(crate) const fn can_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_retries
+    }
+
+    /// Whether a response should be retried, based on its status and the `x-should-retry` header.
+    pub(crate) fn should_retry_response(status: StatusCode, headers: &HeaderMap) -> bool {
+        match headers.get("x-should-retry").and_then(|v| v.to_str().ok()) {
+            Some("true") => return true,
+            Some("false") => return false,
+            _ => {}
+        }
+
+        matches!(
+            status,
+            StatusCode::REQUEST_TIMEOUT | StatusCode::CONFLICT | StatusCode::TOO_MANY_REQUESTS
+        ) || status.is_server_error()
+    }
+
+    /// Whether a transport error should be retried.
+    pub(crate) fn should_retry_error(error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout() || error.is_request()
+    }
+
+    /// The delay before the next retry, preferring the server's `retry-after-ms` or `Retry-After` headers when present.
+    pub(crate) fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        if let Some(delay) = headers.and_then(retry_after) {
+            return delay;
+        }
+
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+
+        // Jitter by up to 25% so concurrent clients don't retry in lockstep.
+        backoff.mul_f64(random_fraction().mul_add(-0.25, 1.0))
+    }
+}
+
+#[cfg(feature = "stream")]
+/// Controls whether [`Client::stream`](crate::Client::stream) reconnects when the connection drops mid-response.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReconnectPolicy {
+    /// Never reconnect. A dropped connection ends the stream with an error.
+    ///
+    /// The request is never re-sent once the stream has opened, so no duplicate responses are created.
+    #[default]
+    Never,
+    /// Re-send the original request, up to `max_attempts` times in a row, if the connection drops before any event was received.
+    ///
+    /// Each attempt creates (and bills) a new response. Once an event has been received, a dropped connection ends the stream.
+    Bounded {
+        /// The maximum number of consecutive reconnection attempts.
+        max_attempts: u32,
+    },
+    /// Resume the stream after the last received event using [`Client::resume_stream`](crate::Client::resume_stream), up to `max_attempts` times in a row.
+    ///
+    /// Only responses created with `background` set to `true` can be resumed.
+    Resume {
+        /// The maximum number of consecutive reconnection attempts.
+        max_attempts: u32,
+    },
+}
+
+/// Parses the `retry-after-ms` and `Retry-After` headers, ignoring values that are negative or unreasonably long.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+
+    let delay = header("retry-after-ms")
+        .and_then(|ms| ms.parse::<f64>().ok())
+        .map(|ms| ms / 1000.0)
+        .or_else(|| {
+            let retry_after = header("retry-after")?;
+
+            retry_after.parse::<f64>().ok().or_else(|| {
+                let date = chrono::DateTime::parse_from_rfc2822(retry_after).ok()?;
+                #[allow(clippy::cast_precision_loss)]
+                let millis =
+                    (date.timestamp_millis() - chrono::Utc::now().timestamp_millis()) as f64;
+                Some(millis / 1000.0)
+            })
+        })?;
+
+    if !(0.0..=MAX_RETRY_AFTER.as_secs_f64()).contains(&delay) {
+        return None;
+    }
+
+    Some(Duration::from_secs_f64(delay))
+}
+
+/// A random number in `[0, 1)`, good enough for jitter without pulling in a RNG crate.
+#[allow(clippy::cast_precision_loss)]
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn retries_only_transient_statuses() {
+        let headers = HeaderMap::new();
+
+        for status in [408, 409, 429, 500, 502, 503] {
+            let status = StatusCode::from_u16(status).unwrap();
+            assert!(RetryPolicy::should_retry_response(status, &headers));
+        }
+        for status in [400, 401, 403, 404, 422] {
+            let status = StatusCode::from_u16(status).unwrap();
+            assert!(!RetryPolicy::should_retry_response(status, &headers));
+        }
+    }
+
+    #[test]
+    fn should_retry_header_overrides_status() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-should-retry", HeaderValue::from_static("false"));
+        assert!(!RetryPolicy::should_retry_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            &headers
+        ));
+
+        headers.insert("x-should-retry", HeaderValue::from_static("true"));
+        assert!(RetryPolicy::should_retry_response(
+            StatusCode::BAD_REQUEST,
+            &headers
+        ));
+    }
+
+    #[test]
+    fn delay_honors_retry_after_headers() {
+        let policy = RetryPolicy::default();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("3"));
+        assert_eq!(policy.delay(0, Some(&headers)), Duration::from_secs(3));
+
+        headers.insert("retry-after-ms", HeaderValue::from_static("250"));
+        assert_eq!(policy.delay(0, Some(&headers)), Duration::from_millis(250));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("3600"));
+        assert!(policy.delay(0, Some(&headers)) <= Duration::from_millis(500));
+    }
+
+    #[test]
+    fn delay_backs_off_exponentially_with_cap() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(4));
+
+        let first = policy.delay(0, None);
+        assert!(first > Duration::from_millis(750) && first <= Duration::from_secs(1));
+
+        let second = policy.delay(1, None);
+        assert!(second > Duration::from_millis(1500) && second <= Duration::from_secs(2));
+
+        let capped = policy.delay(10, None);
+        assert!(capped > Duration::from_secs(3) && capped <= Duration::from_secs(4));
+    }
+}
@@ -0,0 +1,1141 @@
+use serde_json::{Map, Value};
+use std::{collections::HashMap, fmt};
+
+/// The string formats strict mode supports.
+const SUPPORTED_FORMATS: [&str; 9] = [
+    "date-time",
+    "time",
+    "date",
+    "duration",
+    "email",
+    "hostname",
+    "ipv4",
+    "ipv6",
+    "uuid",
+];
+
+/// The types strict mode supports.
+const SUPPORTED_TYPES: [&str; 7] = [
+    "string", "number", "integer", "boolean", "object", "array", "null",
+];
+
+/// The keywords strict mode rejects.
+const UNSUPPORTED_KEYWORDS: [&str; 17] = [
+    "allOf",
+    "oneOf",
+    "not",
+    "if",
+    "then",
+    "else",
+    "dependentRequired",
+    "dependentSchemas",
+    "patternProperties",
+    "unevaluatedProperties",
+    "propertyNames",
+    "minProperties",
+    "maxProperties",
+    "unevaluatedItems",
+    "contains",
+    "minContains",
+    "maxContains",
+];
+
+/// Enums with more than this many values are subject to [`SchemaLimits::max_enum_string_length`].
+const LARGE_ENUM_VALUES: usize = 250;
+
+/// The size limits strict mode places on a schema.
+///
+/// Defaults to the [documented limits](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas).
+///
+/// # Examples
+/// ```rust
+/// use openai_responses::schema::SchemaLimits;
+///
+/// let limits = SchemaLimits::default().max_depth(5).max_enum_values(500);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaLimits {
+    depth: usize,
+    properties: usize,
+    enum_values: usize,
+    string_length: usize,
+    enum_string_length: usize,
+}
+
+impl Default for SchemaLimits {
+    /// Up to 10 levels of nesting, 5000 properties and 1000 enum values, with at most 120,000 characters of
+    /// property names, definition names, enum and const values.
+    fn default() -> Self {
+        Self {
+            depth: 10,
+            properties: 5000,
+            enum_values: 1000,
+            string_length: 120_000,
+            enum_string_length: 15_000,
+        }
+    }
+}
+
+impl SchemaLimits {
+    /// Sets the maximum number of nested levels of objects and arrays.
+    #[must_use]
+    pub const fn max_depth(mut self, max_depth: usize) -> Self {
+        self.depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum number of object properties across the whole schema.
+    #[must_use]
+    pub const fn max_properties(mut self, max_properties: usize) -> Self {
+        self.properties = max_properties;
+        self
+    }
+
+    /// Sets the maximum number of enum values across the whole schema.
+    #[must_use]
+    pub const fn max_enum_values(mut self, max_enum_values: usize) -> Self {
+        self.enum_values = max_enum_values;
+        self
+    }
+
+    /// Sets the maximum total length of property names, definition names, enum and const values.
+    #[must_use]
+    pub const fn max_string_length(mut self, max_string_length: usize) -> Self {
+        self.string_length = max_string_length;
+        self
+    }
+
+    /// Sets the maximum total length of the values of a single enum with more than 250 values.
+    #[must_use]
+    pub const fn max_enum_string_length(mut self, max_enum_string_length: usize) -> Self {
+        self.enum_string_length = max_enum_string_length;
+        self
+    }
+}
+
+/// A schema that strict mode doesn't support.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct SchemaError {
+    /// Every part of the schema strict mode doesn't support.
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((first, rest)) = self.violations.split_first() else {
+            return write!(f, "The schema is not supported in strict mode");
+        };
+
+        write!(f, "The schema is not supported in strict mode: {first}")?;
+        if !rest.is_empty() {
+            write!(f, " (and {} more)", rest.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SchemaError {
+    /// Prefixes the pointer of every violation, for schemas embedded in a larger document.
+    pub(crate) fn nest(mut self, prefix: &str) -> Self {
+        for violation in &mut self.violations {
+            violation.pointer.insert_str(0, prefix);
+        }
+        self
+    }
+}
+
+/// A part of a schema that strict mode doesn't support.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// The [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the offending part of the schema.
+    pub pointer: String,
+    /// What is wrong with it.
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}: {}", self.pointer, self.kind)
+    }
+}
+
+/// What is wrong with a part of a schema.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ViolationKind {
+    /// The value is not a schema object.
+    #[error("expected a schema object")]
+    NotASchema,
+    /// The root schema is not an object.
+    #[error("the root schema must have type `object`")]
+    RootNotObject,
+    /// An object doesn't set `additionalProperties` to `false`.
+    #[error("`additionalProperties` must be `false`")]
+    AdditionalProperties,
+    /// A property is missing from `required`.
+    #[error("property `{0}` must be required")]
+    NotRequired(String),
+    /// A keyword strict mode doesn't support.
+    #[error("`{0}` is not supported")]
+    UnsupportedKeyword(String),
+    /// A type strict mode doesn't support.
+    #[error("type `{0}` is not supported")]
+    UnsupportedType(String),
+    /// A format strict mode doesn't support.
+    #[error("format `{0}` is not supported")]
+    UnsupportedFormat(String),
+    /// A `$ref` that doesn't point within the schema.
+    #[error("`$ref` `{0}` does not resolve within the schema")]
+    UnresolvedRef(String),
+    /// Definitions outside `$defs` at the root of the schema.
+    #[error("definitions must be in `$defs` at the root of the schema")]
+    NestedDefinitions,
+    /// The schema is nested too deeply.
+    #[error("nesting exceeds the limit of {limit} levels")]
+    TooDeep {
+        /// The maximum number of levels.
+        limit: usize,
+    },
+    /// The schema has too many properties.
+    #[error("{count} properties exceed the limit of {limit}")]
+    TooManyProperties {
+        /// The number of properties in the schema.
+        count: usize,
+        /// The maximum number of properties.
+        limit: usize,
+    },
+    /// The schema has too many enum values.
+    #[error("{count} enum values exceed the limit of {limit}")]
+    TooManyEnumValues {
+        /// The number of enum values in the schema.
+        count: usize,
+        /// The maximum number of enum values.
+        limit: usize,
+    },
+    /// The values of a large enum are too long.
+    #[error(
+        "{length} characters of enum values exceed the limit of {limit} for enums with more than 250 values"
+    )]
+    EnumTooLong {
+        /// The total length of the enum's values.
+        length: usize,
+        /// The maximum total length.
+        limit: usize,
+    },
+    /// The names and values in the schema are too long.
+    #[error(
+        "{length} characters of property names, definition names, enum and const values exceed the limit of {limit}"
+    )]
+    TooMuchText {
+        /// The total length of the names and values.
+        length: usize,
+        /// The maximum total length.
+        limit: usize,
+    },
+}
+
+/// Rewrites a schema into the form [strict mode](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas)
+/// expects. Some of these rewrites change what the schema accepts:
+///
+/// - Every object disallows additional properties and requires all of its properties. Properties that weren't
+///   required are not made nullable, so make them nullable yourself if the model should be able to leave them out.
+/// - Definitions are hoisted into `$defs` at the root, and `$ref`s updated to match.
+/// - `oneOf` becomes `anyOf`, and an `allOf` with a single schema is merged into its parent.
+/// - `default` and unsupported formats are removed.
+///
+/// Anything else is left as-is, for [`validate`] to report.
+///
+/// # Examples
+/// ```rust
+/// use openai_responses::schema::{self, SchemaLimits};
+/// use serde_json::json;
+///
+/// let mut schema = json!({
+///     "type": "object",
+///     "properties": { "city": { "type": "string" } },
+/// });
+/// schema::normalize(&mut schema);
+///
+/// assert_eq!(schema["required"], json!(["city"]));
+/// assert!(schema::validate(&schema, &SchemaLimits::default()).is_ok());
+/// ```
+pub fn normalize(schema: &mut Value) {
+    hoist_definitions(schema);
+    make_strict(schema);
+}
+
+/// Checks a schema against the restrictions and size limits of [strict mode](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas).
+///
+/// Run [`normalize`] first to fix what can be fixed automatically.
+///
+/// ## Errors
+///
+/// Errors with every violation found, each with a JSON pointer to where it occurs. See [`SchemaError`].
+pub fn validate(schema: &Value, limits: &SchemaLimits) -> Result<(), SchemaError> {
+    let mut validator = Validator {
+        root: schema,
+        limits: *limits,
+        too_deep: false,
+        heights: HashMap::new(),
+        properties: 0,
+        enum_values: 0,
+        string_length: 0,
+        violations: Vec::new(),
+    };
+
+    if !matches!(schema.get("type"), Some(Value::String(t)) if t == "object") {
+        validator.report(String::new(), ViolationKind::RootNotObject);
+    }
+    validator.check(schema, String::new(), 1);
+    validator.check_totals();
+
+    if validator.violations.is_empty() {
+        Ok(())
+    } else {
+        Err(SchemaError {
+            violations: validator.violations,
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+/// Generates the JSON schema for `T`, normalized for strict mode.
+pub(crate) fn strict_schema_for<T: schemars::JsonSchema>() -> Value {
+    let mut schema = schemars::generate::SchemaSettings::draft2020_12()
+        .for_deserialize()
+        .into_generator()
+        .into_root_schema_for::<T>()
+        .to_value();
+
+    if let Some(schema) = schema.as_object_mut() {
+        schema.remove("$schema");
+    }
+    normalize(&mut schema);
+
+    schema
+}
+
+#[cfg(feature = "schemars")]
+/// Turns a schema name into a valid response format name, which may only contain a-z, A-Z, 0-9, underscores and dashes,
+/// with a maximum length of 64.
+pub(crate) fn format_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(64)
+        .collect()
+}
+
+/// Moves `definitions` and any nested `$defs` into `$defs` at the root, updating `$ref`s to match.
+fn hoist_definitions(schema: &mut Value) {
+    // Hoisting can uncover definitions nested in other definitions, so repeat until none are left.
+    loop {
+        let Some(root) = schema.as_object_mut() else {
+            return;
+        };
+
+        let mut found = Vec::new();
+        if let Some(Value::Object(definitions)) = root.remove("definitions") {
+            for (name, definition) in definitions {
+                found.push((format!("/definitions/{}", escape(&name)), name, definition));
+            }
+        }
+        for_each_subschema(schema, "", &mut |value, pointer| {
+            take_definitions(value, pointer, &mut found);
+        });
+
+        let Some(root) = schema.as_object_mut() else {
+            return;
+        };
+        if let Some(Value::Object(defs)) = root.get_mut("$defs") {
+            for (name, value) in defs.iter_mut() {
+                take_definitions(value, &format!("/$defs/{}", escape(name)), &mut found);
+            }
+        }
+        if found.is_empty() {
+            return;
+        }
+
+        let Value::Object(defs) = root
+            .entry("$defs")
+            .or_insert_with(|| Value::Object(Map::new()))
+        else {
+            return;
+        };
+
+        let mut renames = Vec::new();
+        for (pointer, name, definition) in found {
+            let name = unique_name(defs, &name);
+            renames.push((format!("#{pointer}"), format!("#/$defs/{}", escape(&name))));
+            defs.insert(name, definition);
+        }
+        rewrite_refs(schema, &renames);
+    }
+}
+
+/// Removes the `$defs` and `definitions` maps of `value` and its subschemas, collecting the pointer, name and schema of each definition.
+fn take_definitions(value: &mut Value, pointer: &str, found: &mut Vec<(String, String, Value)>) {
+    if let Value::Object(object) = value {
+        for key in ["$defs", "definitions"] {
+            if let Some(Value::Object(definitions)) = object.remove(key) {
+                for (name, definition) in definitions {
+                    let pointer = format!("{pointer}/{key}/{}", escape(&name));
+                    found.push((pointer, name, definition));
+                }
+            }
+        }
+    }
+
+    for_each_subschema(value, pointer, &mut |value, pointer| {
+        take_definitions(value, pointer, found);
+    });
+}
+
+/// Calls `f` with every direct subschema of `schema` outside its definitions, along with its pointer.
+///
+/// Only keywords whose values are schemas are followed, so property names, `enum` and `const` values are left alone.
+fn for_each_subschema(schema: &mut Value, pointer: &str, f: &mut impl FnMut(&mut Value, &str)) {
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+
+    for (key, value) in object.iter_mut() {
+        let pointer = format!("{pointer}/{}", escape(key));
+        match key.as_str() {
+            "items" | "additionalProperties" => f(value, &pointer),
+            "anyOf" | "allOf" | "oneOf" | "prefixItems" => {
+                for (index, value) in value.as_array_mut().into_iter().flatten().enumerate() {
+                    f(value, &format!("{pointer}/{index}"));
+                }
+            }
+            "properties" => {
+                for (name, value) in value.as_object_mut().into_iter().flatten() {
+                    f(value, &format!("{pointer}/{}", escape(name)));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Rewrites every `$ref` pointing at or below one of the old pointers to point at the matching new one.
+fn rewrite_refs(value: &mut Value, renames: &[(String, String)]) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get_mut("$ref")
+                && let Some((old, new)) = renames.iter().find(|(old, _)| {
+                    reference
+                        .strip_prefix(old.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+                })
+            {
+                *reference = format!("{new}{}", &reference[old.len()..]);
+            }
+            for value in object.values_mut() {
+                rewrite_refs(value, renames);
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                rewrite_refs(value, renames);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `name`, suffixed with a number if a definition by that name already exists.
+fn unique_name(defs: &Map<String, Value>, name: &str) -> String {
+    if !defs.contains_key(name) {
+        return name.to_string();
+    }
+
+    let mut n = 2;
+    while defs.contains_key(&format!("{name}{n}")) {
+        n += 1;
+    }
+
+    format!("{name}{n}")
+}
+
+/// Rewrites a schema and its subschemas so every object disallows additional properties and requires all of them.
+fn make_strict(schema: &mut Value) {
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+
+    // Some generators wrap a `$ref` in a single-schema `allOf` to attach a description to it.
+    if let Some(Value::Array(all_of)) = object.get("allOf")
+        && let [Value::Object(inner)] = all_of.as_slice()
+        && inner.keys().all(|key| !object.contains_key(key))
+    {
+        let inner = inner.clone();
+        object.remove("allOf");
+        object.extend(inner);
+    }
+
+    for (key, value) in object.iter_mut() {
+        match key.as_str() {
+            "items" | "additionalProperties" => make_strict(value),
+            "anyOf" | "allOf" | "oneOf" | "prefixItems" => {
+                value
+                    .as_array_mut()
+                    .into_iter()
+                    .flatten()
+                    .for_each(make_strict);
+            }
+            "properties" | "$defs" | "definitions" => {
+                value
+                    .as_object_mut()
+                    .into_iter()
+                    .flat_map(Map::values_mut)
+                    .for_each(make_strict);
+            }
+            _ => {}
+        }
+    }
+
+    // Strict mode supports `anyOf` but not `oneOf`, which schemars uses for enums with data.
+    if !object.contains_key("anyOf")
+        && let Some(one_of) = object.remove("oneOf")
+    {
+        object.insert("anyOf".to_string(), one_of);
+    }
+
+    // Every property is required, so defaults never apply. `Option` fields are already nullable.
+    object.remove("default");
+
+    // Integer formats such as `uint32` and string formats such as `uri` aren't supported.
+    if object
+        .get("format")
+        .and_then(Value::as_str)
+        .is_some_and(|format| !is_type(object, "string") || !SUPPORTED_FORMATS.contains(&format))
+    {
+        object.remove("format");
+    }
+
+    let Some(Value::Object(properties)) = object.get("properties") else {
+        return;
+    };
+    let names = properties.keys().cloned().map(Value::String).collect();
+
+    object.insert("required".to_string(), Value::Array(names));
+    object.insert("additionalProperties".to_string(), Value::Bool(false));
+}
+
+/// Walks a schema, collecting violations and the totals the limits apply to.
+struct Validator<'a> {
+    root: &'a Value,
+    limits: SchemaLimits,
+    too_deep: bool,
+    /// The heights of the schemas `$ref`s point to, by reference.
+    heights: HashMap<&'a str, usize>,
+    properties: usize,
+    enum_values: usize,
+    string_length: usize,
+    violations: Vec<Violation>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, pointer: String, kind: ViolationKind) {
+        self.violations.push(Violation { pointer, kind });
+    }
+
+    fn check(&mut self, schema: &'a Value, pointer: String, depth: usize) {
+        let Some(object) = schema.as_object() else {
+            self.report(pointer, ViolationKind::NotASchema);
+            return;
+        };
+
+        if depth > self.limits.depth && !self.too_deep {
+            self.too_deep = true;
+            self.report(
+                pointer.clone(),
+                ViolationKind::TooDeep {
+                    limit: self.limits.depth,
+                },
+            );
+        }
+
+        for key in object.keys() {
+            if UNSUPPORTED_KEYWORDS.contains(&key.as_str()) {
+                self.report(
+                    format!("{pointer}/{}", escape(key)),
+                    ViolationKind::UnsupportedKeyword(key.clone()),
+                );
+            }
+        }
+
+        self.check_type(object, &pointer);
+        self.check_values(object, &pointer);
+
+        if let Some(Value::String(reference)) = object.get("$ref") {
+            if self.resolve(reference).is_none() {
+                self.report(
+                    format!("{pointer}/$ref"),
+                    ViolationKind::UnresolvedRef(reference.clone()),
+                );
+            } else if !self.too_deep
+                && depth + self.ref_height(reference, &mut Vec::new()) - 1 > self.limits.depth
+            {
+                // Definitions are checked from depth 1, so count their nesting from where they're used too.
+                self.too_deep = true;
+                self.report(
+                    format!("{pointer}/$ref"),
+                    ViolationKind::TooDeep {
+                        limit: self.limits.depth,
+                    },
+                );
+            }
+        }
+
+        if object.contains_key("properties") || is_type(object, "object") {
+            self.check_object(object, &pointer, depth);
+        }
+        if let Some(items) = object.get("items") {
+            self.check(items, format!("{pointer}/items"), depth + 1);
+        }
+        for key in ["anyOf", "allOf", "oneOf"] {
+            for (index, schema) in object
+                .get(key)
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .enumerate()
+            {
+                self.check(schema, format!("{pointer}/{key}/{index}"), depth);
+            }
+        }
+
+        for key in ["$defs", "definitions"] {
+            let Some(definitions) = object.get(key) else {
+                continue;
+            };
+            if !pointer.is_empty() || key == "definitions" {
+                self.report(format!("{pointer}/{key}"), ViolationKind::NestedDefinitions);
+                continue;
+            }
+
+            for (name, definition) in definitions.as_object().into_iter().flatten() {
+                self.string_length += name.chars().count();
+                self.check(definition, format!("/$defs/{}", escape(name)), 1);
+            }
+        }
+    }
+
+    /// The number of nested levels of objects and arrays in `schema`, counting itself and following `$ref`s.
+    ///
+    /// References already being followed are skipped, so recursive schemas only count one pass through the cycle.
+    fn height(&mut self, schema: &'a Value, visiting: &mut Vec<&'a str>) -> usize {
+        let Some(object) = schema.as_object() else {
+            return 1;
+        };
+
+        let mut height = 1;
+        let properties = object.get("properties").and_then(Value::as_object);
+        for child in properties
+            .into_iter()
+            .flat_map(Map::values)
+            .chain(object.get("items"))
+        {
+            height = height.max(1 + self.height(child, visiting));
+        }
+        for key in ["anyOf", "allOf", "oneOf"] {
+            for schema in object
+                .get(key)
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                height = height.max(self.height(schema, visiting));
+            }
+        }
+        if let Some(Value::String(reference)) = object.get("$ref") {
+            height = height.max(self.ref_height(reference, visiting));
+        }
+
+        height
+    }
+
+    /// The [height](Self::height) of the schema `reference` points to, or 0 if it doesn't resolve or is being followed.
+    fn ref_height(&mut self, reference: &'a str, visiting: &mut Vec<&'a str>) -> usize {
+        if let Some(&height) = self.heights.get(reference) {
+            return height;
+        }
+        let Some(target) = self.resolve(reference) else {
+            return 0;
+        };
+        if visiting.contains(&reference) {
+            return 0;
+        }
+
+        visiting.push(reference);
+        let height = self.height(target, visiting);
+        visiting.pop();
+        self.heights.insert(reference, height);
+
+        height
+    }
+
+    fn check_type(&mut self, object: &Map<String, Value>, pointer: &str) {
+        let types = match object.get("type") {
+            Some(Value::String(r#type)) => vec![r#type.as_str()],
+            Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        for r#type in types {
+            if !SUPPORTED_TYPES.contains(&r#type) {
+                self.report(
+                    format!("{pointer}/type"),
+                    ViolationKind::UnsupportedType(r#type.to_string()),
+                );
+            }
+        }
+
+        if let Some(format) = object.get("format").and_then(Value::as_str)
+            && (!is_type(object, "string") || !SUPPORTED_FORMATS.contains(&format))
+        {
+            self.report(
+                format!("{pointer}/format"),
+                ViolationKind::UnsupportedFormat(format.to_string()),
+            );
+        }
+    }
+
+    fn check_values(&mut self, object: &Map<String, Value>, pointer: &str) {
+        if let Some(Value::Array(values)) = object.get("enum") {
+            let length = values
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|value| value.chars().count())
+                .sum::<usize>();
+
+            self.enum_values += values.len();
+            self.string_length += length;
+            if values.len() > LARGE_ENUM_VALUES && length > self.limits.enum_string_length {
+                self.report(
+                    format!("{pointer}/enum"),
+                    ViolationKind::EnumTooLong {
+                        length,
+                        limit: self.limits.enum_string_length,
+                    },
+                );
+            }
+        }
+
+        if let Some(Value::String(value)) = object.get("const") {
+            self.string_length += value.chars().count();
+        }
+    }
+
+    fn check_object(&mut self, object: &'a Map<String, Value>, pointer: &str, depth: usize) {
+        if object.get("additionalProperties") != Some(&Value::Bool(false)) {
+            self.report(
+                format!("{pointer}/additionalProperties"),
+                ViolationKind::AdditionalProperties,
+            );
+        }
+
+        let required = object.get("required").and_then(Value::as_array);
+        let properties = object.get("properties").and_then(Value::as_object);
+        for (name, property) in properties.into_iter().flatten() {
+            let pointer = format!("{pointer}/properties/{}", escape(name));
+
+            self.properties += 1;
+            self.string_length += name.chars().count();
+            if !required.is_some_and(|required| required.iter().any(|r| r == name)) {
+                self.report(pointer.clone(), ViolationKind::NotRequired(name.clone()));
+            }
+            self.check(property, pointer, depth + 1);
+        }
+    }
+
+    fn check_totals(&mut self) {
+        let limits = self.limits;
+
+        if self.properties > limits.properties {
+            self.report(
+                String::new(),
+                ViolationKind::TooManyProperties {
+                    count: self.properties,
+                    limit: limits.properties,
+                },
+            );
+        }
+        if self.enum_values > limits.enum_values {
+            self.report(
+                String::new(),
+                ViolationKind::TooManyEnumValues {
+                    count: self.enum_values,
+                    limit: limits.enum_values,
+                },
+            );
+        }
+        if self.string_length > limits.string_length {
+            self.report(
+                String::new(),
+                ViolationKind::TooMuchText {
+                    length: self.string_length,
+                    limit: limits.string_length,
+                },
+            );
+        }
+    }
+
+    /// The value within the root schema `reference` points to.
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        if reference == "#" {
+            return Some(self.root);
+        }
+
+        reference
+            .strip_prefix('#')
+            .filter(|pointer| pointer.starts_with('/'))
+            .and_then(|pointer| self.root.pointer(pointer))
+    }
+}
+
+/// Whether the schema's `type` is, or includes, `r#type`.
+fn is_type(object: &Map<String, Value>, r#type: &str) -> bool {
+    match object.get("type") {
+        Some(Value::String(t)) => t == r#type,
+        Some(Value::Array(types)) => types.iter().any(|t| t == r#type),
+        _ => false,
+    }
+}
+
+/// Escapes a key for use in a JSON pointer.
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn normalize_makes_objects_strict_and_hoists_definitions() {
+        let mut schema = json!({
+            "type": "object",
+            "properties": {
+                "website": { "type": "string", "format": "uri" },
+                "created": { "type": "string", "format": "date-time" },
+                "owner": { "allOf": [{ "$ref": "#/definitions/Person" }], "description": "Who owns it." },
+                "tags": {
+                    "type": "array",
+                    "items": { "$ref": "#/properties/tags/$defs/Tag" },
+                    "$defs": {
+                        "Tag": { "type": "object", "properties": { "name": { "type": "string", "default": "" } } },
+                    },
+                },
+                "status": { "oneOf": [{ "const": "open" }, { "const": "closed" }] },
+            },
+            "required": ["owner"],
+            "definitions": {
+                "Person": { "type": "object", "properties": { "age": { "type": "integer", "format": "uint8" } } },
+            },
+        });
+
+        normalize(&mut schema);
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "object",
+                "properties": {
+                    "website": { "type": "string" },
+                    "created": { "type": "string", "format": "date-time" },
+                    "owner": { "$ref": "#/$defs/Person", "description": "Who owns it." },
+                    "tags": { "type": "array", "items": { "$ref": "#/$defs/Tag" } },
+                    "status": { "anyOf": [{ "const": "open" }, { "const": "closed" }] },
+                },
+                "required": ["created", "owner", "status", "tags", "website"],
+                "additionalProperties": false,
+                "$defs": {
+                    "Person": {
+                        "type": "object",
+                        "properties": { "age": { "type": "integer" } },
+                        "required": ["age"],
+                        "additionalProperties": false,
+                    },
+                    "Tag": {
+                        "type": "object",
+                        "properties": { "name": { "type": "string" } },
+                        "required": ["name"],
+                        "additionalProperties": false,
+                    },
+                },
+            })
+        );
+        assert_eq!(validate(&schema, &SchemaLimits::default()), Ok(()));
+    }
+
+    #[test]
+    fn normalize_renames_clashing_definitions() {
+        let mut schema = json!({
+            "type": "object",
+            "properties": {
+                "a": { "$ref": "#/$defs/Item" },
+                "b": {
+                    "type": "object",
+                    "properties": { "item": { "$ref": "#/properties/b/$defs/Item" } },
+                    "$defs": { "Item": { "type": "number" } },
+                },
+            },
+            "$defs": { "Item": { "type": "string" } },
+        });
+
+        normalize(&mut schema);
+
+        assert_eq!(schema["properties"]["a"]["$ref"], "#/$defs/Item");
+        assert_eq!(
+            schema["properties"]["b"]["properties"]["item"]["$ref"],
+            "#/$defs/Item2"
+        );
+        assert_eq!(
+            schema["$defs"],
+            json!({ "Item": { "type": "string" }, "Item2": { "type": "number" } })
+        );
+        assert_eq!(validate(&schema, &SchemaLimits::default()), Ok(()));
+    }
+
+    #[test]
+    fn normalize_only_hoists_definitions_from_schemas() {
+        let mut schema = json!({
+            "type": "object",
+            "properties": {
+                "definitions": {
+                    "type": "object",
+                    "properties": { "term": { "type": "string" } },
+                },
+                "kind": { "const": { "$defs": "literal" } },
+            },
+        });
+
+        normalize(&mut schema);
+
+        assert_eq!(
+            schema["properties"],
+            json!({
+                "definitions": {
+                    "type": "object",
+                    "properties": { "term": { "type": "string" } },
+                    "required": ["term"],
+                    "additionalProperties": false,
+                },
+                "kind": { "const": { "$defs": "literal" } },
+            })
+        );
+        assert!(schema.get("$defs").is_none());
+    }
+
+    #[test]
+    fn validate_reports_violations_with_pointers() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "format": "uri" },
+                "a/b": { "type": "object", "properties": { "x": { "type": "integer" } }, "required": ["x"] },
+                "choice": { "not": { "type": "null" } },
+                "friend": { "$ref": "#/$defs/Friend" },
+            },
+            "required": ["name", "choice", "friend"],
+            "additionalProperties": false,
+        });
+
+        let error = validate(&schema, &SchemaLimits::default()).unwrap_err();
+
+        assert_eq!(
+            error
+                .violations
+                .iter()
+                .map(|violation| (violation.pointer.as_str(), &violation.kind))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "/properties/a~1b",
+                    &ViolationKind::NotRequired("a/b".to_string())
+                ),
+                (
+                    "/properties/a~1b/additionalProperties",
+                    &ViolationKind::AdditionalProperties
+                ),
+                (
+                    "/properties/choice/not",
+                    &ViolationKind::UnsupportedKeyword("not".to_string())
+                ),
+                (
+                    "/properties/friend/$ref",
+                    &ViolationKind::UnresolvedRef("#/$defs/Friend".to_string())
+                ),
+                (
+                    "/properties/name/format",
+                    &ViolationKind::UnsupportedFormat("uri".to_string())
+                ),
+            ]
+        );
+        assert_eq!(
+            error.to_string(),
+            "The schema is not supported in strict mode: #/properties/a~1b: property `a/b` must be required (and 4 more)"
+        );
+
+        let error = validate(&json!({ "anyOf": [] }), &SchemaLimits::default()).unwrap_err();
+        assert_eq!(error.violations[0].pointer, "");
+        assert_eq!(error.violations[0].kind, ViolationKind::RootNotObject);
+    }
+
+    #[test]
+    fn validate_checks_limits() {
+        let mut nested = json!({ "type": "string" });
+        for _ in 0..3 {
+            nested = json!({ "type": "object", "properties": { "inner": nested } });
+        }
+        normalize(&mut nested);
+
+        let limits = SchemaLimits::default().max_depth(3);
+        let error = validate(&nested, &limits).unwrap_err();
+        assert_eq!(
+            error.violations,
+            [Violation {
+                pointer: "/properties/inner/properties/inner/properties/inner".to_string(),
+                kind: ViolationKind::TooDeep { limit: 3 },
+            }]
+        );
+        assert_eq!(validate(&nested, &limits.max_depth(4)), Ok(()));
+
+        let schema = json!({
+            "type": "object",
+            "properties": { "outer": { "$ref": "#/$defs/Nested" } },
+            "required": ["outer"],
+            "additionalProperties": false,
+            "$defs": { "Nested": nested },
+        });
+        let error = validate(&schema, &limits.max_depth(4)).unwrap_err();
+        assert_eq!(
+            error.violations,
+            [Violation {
+                pointer: "/properties/outer/$ref".to_string(),
+                kind: ViolationKind::TooDeep { limit: 4 },
+            }]
+        );
+        assert_eq!(validate(&schema, &limits.max_depth(5)), Ok(()));
+
+        let recursive = json!({
+            "type": "object",
+            "properties": { "children": { "type": "array", "items": { "$ref": "#" } } },
+            "required": ["children"],
+            "additionalProperties": false,
+        });
+        assert_eq!(validate(&recursive, &SchemaLimits::default()), Ok(()));
+
+        let values = (0..300)
+            .map(|n| format!("value-{n:04}"))
+            .collect::<Vec<_>>();
+        let mut schema = json!({
+            "type": "object",
+            "properties": { "a": { "enum": values }, "b": { "enum": values } },
+        });
+        normalize(&mut schema);
+
+        let limits = SchemaLimits::default()
+            .max_properties(1)
+            .max_enum_values(500)
+            .max_enum_string_length(2999)
+            .max_string_length(6000);
+        let kinds = validate(&schema, &limits)
+            .unwrap_err()
+            .violations
+            .into_iter()
+            .map(|violation| violation.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                ViolationKind::EnumTooLong {
+                    length: 3000,
+                    limit: 2999
+                },
+                ViolationKind::EnumTooLong {
+                    length: 3000,
+                    limit: 2999
+                },
+                ViolationKind::TooManyProperties { count: 2, limit: 1 },
+                ViolationKind::TooManyEnumValues {
+                    count: 600,
+                    limit: 500
+                },
+                ViolationKind::TooMuchText {
+                    length: 6002,
+                    limit: 6000
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn function_tool_generates_strict_schema() {
+        use crate::types::Tool;
+
+        #[allow(dead_code)]
+        #[derive(schemars::JsonSchema)]
+        struct SearchFlights {
+            /// IATA code of the departure airport.
+            from: String,
+            to: String,
+            passengers: u32,
+            cabin: Option<Cabin>,
+            return_date: Option<String>,
+            #[serde(default)]
+            flexible: bool,
+        }
+
+        #[allow(dead_code)]
+        #[derive(schemars::JsonSchema)]
+        #[serde(rename_all = "lowercase")]
+        enum Cabin {
+            Economy,
+            Business,
+        }
+
+        let Tool::Function {
+            name,
+            parameters,
+            strict,
+            description,
+        } = Tool::function::<SearchFlights>("search_flights", "Search for flights.")
+        else {
+            panic!("expected a function tool");
+        };
+        assert_eq!(name, "search_flights");
+        assert_eq!(description.as_deref(), Some("Search for flights."));
+        assert!(strict);
+
+        assert_eq!(
+            parameters,
+            json!({
+                "title": "SearchFlights",
+                "type": "object",
+                "properties": {
+                    "from": { "type": "string", "description": "IATA code of the departure airport." },
+                    "to": { "type": "string" },
+                    "passengers": { "type": "integer", "minimum": 0 },
+                    "cabin": { "anyOf": [{ "$ref": "#/$defs/Cabin" }, { "type": "null" }] },
+                    "return_date": { "type": ["string", "null"] },
+                    "flexible": { "type": "boolean" },
+                },
+                "required": ["cabin", "flexible", "from", "passengers", "return_date", "to"],
+                "additionalProperties": false,
+                "$defs": {
+                    "Cabin": { "type": "string", "enum": ["economy", "business"] },
+                },
+            })
+        );
+    }
+}
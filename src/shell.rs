@@ -0,0 +1,464 @@
+use std::{future::Future, time::Duration};
+
+use crate::types::{
+    InputItem, LocalShellAction, LocalShellCall, LocalShellCallOutput, LocalShellExecAction,
+};
+#[cfg(feature = "local-shell")]
+use {
+    std::{
+        collections::HashSet,
+        path::{Path, PathBuf},
+        process::Stdio,
+    },
+    tokio::{
+        io::{AsyncRead, AsyncReadExt},
+        process::Command,
+    },
+};
+
+/// Runs the commands requested by the model through the [local shell tool](https://platform.openai.com/docs/guides/tools-local-shell).
+///
+/// Implement this to run commands in a sandbox, container or remote machine. Enable the `local-shell` feature for
+/// [`LocalShellExecutor`], which runs them as child processes.
+pub trait ShellExecutor: Send + Sync {
+    /// Runs an `exec` action requested by the model.
+    fn exec(
+        &self,
+        action: &LocalShellExecAction,
+    ) -> impl Future<Output = Result<ShellOutput, ShellError>> + Send;
+}
+
+/// The result of running a shell command.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShellOutput {
+    /// The standard output of the command.
+    pub stdout: String,
+    /// The standard error of the command.
+    pub stderr: String,
+    /// The exit code of the command, or `None` if it was terminated by a signal.
+    pub exit_code: Option<i32>,
+    /// Whether the output was cut short because it exceeded the executor's size limit.
+    pub truncated: bool,
+}
+
+impl ShellOutput {
+    /// The output to send back to the model: stdout, then stderr under a `[stderr]` label, then the exit status,
+    /// noting if the output was truncated.
+    #[must_use]
+    pub fn to_model_output(&self) -> String {
+        let mut output = self.stdout.clone();
+
+        if !self.stderr.is_empty() {
+            end_line(&mut output);
+            output.push_str("[stderr]\n");
+            output.push_str(&self.stderr);
+        }
+
+        end_line(&mut output);
+        output.push_str(&self.exit_code.map_or_else(
+            || "[terminated by a signal]".to_string(),
+            |code| format!("[exit code: {code}]"),
+        ));
+
+        if self.truncated {
+            output.push_str("\n[output truncated]");
+        }
+
+        output
+    }
+}
+
+/// Starts a new line in `output`, unless it is empty or already ends with one.
+fn end_line(output: &mut String) {
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+/// Errors that can occur when running a shell command.
+#[derive(Debug, thiserror::Error)]
+pub enum ShellError {
+    /// The model requested an empty command.
+    #[error("The command is empty")]
+    EmptyCommand,
+    /// The command is not in the executor's allowlist.
+    #[error("The command `{0}` is not allowed")]
+    NotAllowed(String),
+    /// The command tried to set an environment variable that is not in the executor's allowlist.
+    #[error("Setting the environment variable `{0}` is not allowed")]
+    EnvNotAllowed(String),
+    /// The command is not an executable file in the current process's `PATH`.
+    #[error("The command `{0}` was not found")]
+    NotFound(String),
+    /// The command asked to run as a different user, which the executor does not support.
+    #[error("Running commands as user `{0}` is not supported")]
+    UnsupportedUser(String),
+    /// The command did not finish before the timeout elapsed, and was killed.
+    #[error("The command timed out after {0:?}")]
+    Timeout(Duration),
+    /// The command could not be started, or its output could not be read.
+    #[error("Failed to run the command: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl LocalShellCall {
+    /// Runs this call with the given executor, returning the output item to send back to the model in the next request.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the executor fails to run the command. See [`ShellError`].
+    pub async fn run(&self, executor: &impl ShellExecutor) -> Result<InputItem, ShellError> {
+        let output = match &self.action {
+            LocalShellAction::Exec(action) => executor.exec(action).await?,
+        };
+
+        Ok(InputItem::LocalShellCallOutput(LocalShellCallOutput {
+            id: None,
+            status: None,
+            call_id: self.call_id.clone(),
+            output: output.to_model_output(),
+            extra: serde_json::Map::new(),
+        }))
+    }
+}
+
+#[cfg(feature = "local-shell")]
+/// A [`ShellExecutor`] that runs commands as child processes of the current process.
+///
+/// Only commands whose first argument exactly matches an entry in the allowlist are run. Note that allowing a shell
+/// or interpreter such as `bash` or `python` effectively allows any command.
+///
+/// Commands are looked up in the `PATH` of the current process, and run with an empty environment. The model may only
+/// set the variables allowed with [`allowed_env`](Self::allowed_env); requests that set any other variable are refused.
+/// Many programs run code named by their environment, such as `git` with `GIT_SSH_COMMAND` or `bash` with `BASH_ENV`,
+/// so only allow variables the allowed commands can't be made to misuse.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use openai_responses::LocalShellExecutor;
+///
+/// let executor = LocalShellExecutor::new(["ls", "cat", "grep"])
+///     .allowed_env(["LANG"])
+///     .timeout(Duration::from_secs(30))
+///     .max_output_bytes(64 * 1024);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalShellExecutor {
+    allowed_commands: HashSet<String>,
+    allowed_env: HashSet<String>,
+    timeout: Duration,
+    max_output_bytes: usize,
+    working_directory: Option<PathBuf>,
+}
+
+#[cfg(feature = "local-shell")]
+impl LocalShellExecutor {
+    /// Creates an executor that only runs the given commands, with a 60 second timeout and a 1 MiB output limit.
+    ///
+    /// The model can't set any environment variables until they're allowed with [`allowed_env`](Self::allowed_env).
+    #[must_use]
+    pub fn new(allowed_commands: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            allowed_env: HashSet::new(),
+            working_directory: None,
+            max_output_bytes: 1024 * 1024,
+            timeout: Duration::from_mins(1),
+            allowed_commands: allowed_commands.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Sets the environment variables the model may set for a command. Commands otherwise run with an empty environment.
+    #[must_use]
+    pub fn allowed_env(mut self, allowed_env: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.allowed_env = allowed_env.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the longest a command may run. Commands requesting a shorter `timeout_ms` use theirs instead.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum number of bytes kept from each of stdout and stderr. Anything past it is discarded.
+    #[must_use]
+    pub const fn max_output_bytes(mut self, max_output_bytes: usize) -> Self {
+        self.max_output_bytes = max_output_bytes;
+        self
+    }
+
+    /// Sets the directory commands run in when they don't request one. Defaults to the current directory.
+    #[must_use]
+    pub fn working_directory(mut self, working_directory: impl Into<PathBuf>) -> Self {
+        self.working_directory = Some(working_directory.into());
+        self
+    }
+
+    async fn run(&self, action: &LocalShellExecAction) -> Result<ShellOutput, ShellError> {
+        let Some((program, args)) = action.command.split_first() else {
+            return Err(ShellError::EmptyCommand);
+        };
+        if !self.allowed_commands.contains(program) {
+            return Err(ShellError::NotAllowed(program.clone()));
+        }
+        if let Some(user) = &action.user {
+            return Err(ShellError::UnsupportedUser(user.clone()));
+        }
+        if let Some(name) = action
+            .env
+            .keys()
+            .find(|name| !self.allowed_env.contains(*name))
+        {
+            return Err(ShellError::EnvNotAllowed(name.clone()));
+        }
+
+        let mut command = Command::new(resolve_program(program)?);
+        command
+            .args(args)
+            .env_clear()
+            .envs(&action.env)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        if let Some(dir) = action
+            .working_directory
+            .as_deref()
+            .map(PathBuf::from)
+            .or_else(|| self.working_directory.clone())
+        {
+            command.current_dir(dir);
+        }
+
+        let timeout = action.timeout_ms.map_or(self.timeout, |ms| {
+            self.timeout.min(Duration::from_millis(ms))
+        });
+
+        let mut child = command.spawn()?;
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let stderr = child.stderr.take().expect("stderr is piped");
+
+        let output = tokio::time::timeout(timeout, async {
+            tokio::try_join!(
+                read_capped(stdout, self.max_output_bytes),
+                read_capped(stderr, self.max_output_bytes),
+                child.wait(),
+            )
+        })
+        .await;
+
+        let Ok(output) = output else {
+            child.kill().await?;
+            return Err(ShellError::Timeout(timeout));
+        };
+        let ((stdout, stdout_truncated), (stderr, stderr_truncated), status) = output?;
+
+        Ok(ShellOutput {
+            exit_code: status.code(),
+            truncated: stdout_truncated || stderr_truncated,
+            stdout: String::from_utf8_lossy(&stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&stderr).into_owned(),
+        })
+    }
+}
+
+#[cfg(feature = "local-shell")]
+impl ShellExecutor for LocalShellExecutor {
+    fn exec(
+        &self,
+        action: &LocalShellExecAction,
+    ) -> impl Future<Output = Result<ShellOutput, ShellError>> + Send {
+        self.run(action)
+    }
+}
+
+/// Finds `program` in the current process's `PATH`, since commands don't inherit it.
+#[cfg(feature = "local-shell")]
+fn resolve_program(program: &str) -> Result<PathBuf, ShellError> {
+    if program.contains(std::path::MAIN_SEPARATOR) {
+        return Ok(PathBuf::from(program));
+    }
+
+    std::env::var_os("PATH")
+        .iter()
+        .flat_map(std::env::split_paths)
+        .flat_map(|dir| {
+            [
+                dir.join(program),
+                dir.join(format!("{program}{}", std::env::consts::EXE_SUFFIX)),
+            ]
+        })
+        .find(|path| is_executable(path))
+        .ok_or_else(|| ShellError::NotFound(program.to_string()))
+}
+
+#[cfg(all(feature = "local-shell", unix))]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(all(feature = "local-shell", not(unix)))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Reads up to `limit` bytes from `reader`, then drains the rest so the child process doesn't block on a full pipe.
+#[cfg(feature = "local-shell")]
+async fn read_capped(
+    mut reader: impl AsyncRead + Unpin,
+    limit: usize,
+) -> std::io::Result<(Vec<u8>, bool)> {
+    let mut buf = Vec::new();
+    (&mut reader)
+        .take(limit as u64)
+        .read_to_end(&mut buf)
+        .await?;
+    let truncated = tokio::io::copy(&mut reader, &mut tokio::io::sink()).await? > 0;
+
+    Ok((buf, truncated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::OutputItem;
+
+    struct EchoExecutor;
+
+    impl ShellExecutor for EchoExecutor {
+        async fn exec(&self, action: &LocalShellExecAction) -> Result<ShellOutput, ShellError> {
+            Ok(ShellOutput {
+                stdout: action.command.join(" "),
+                exit_code: Some(0),
+                ..Default::default()
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn run_returns_output_item() {
+        let item: OutputItem = serde_json::from_value(serde_json::json!({
+            "type": "local_shell_call",
+            "id": "lsh_123",
+            "call_id": "call_123",
+            "status": "completed",
+            "action": {
+                "type": "exec",
+                "command": ["echo", "hi"],
+                "env": {},
+                "timeout_ms": 1000,
+            },
+        }))
+        .unwrap();
+        let OutputItem::LocalShellCall(call) = item else {
+            panic!("expected a local shell call");
+        };
+
+        let item = call.run(&EchoExecutor).await.unwrap();
+
+        assert_eq!(
+            serde_json::to_value(item).unwrap(),
+            serde_json::json!({ "type": "local_shell_call_output", "call_id": "call_123", "output": "echo hi\n[exit code: 0]" })
+        );
+    }
+
+    #[test]
+    fn model_output_labels_stderr_and_exit_status() {
+        let output = ShellOutput {
+            stdout: "partial".to_string(),
+            stderr: "error: no such file\n".to_string(),
+            exit_code: Some(2),
+            truncated: true,
+        };
+        assert_eq!(
+            output.to_model_output(),
+            "partial\n[stderr]\nerror: no such file\n[exit code: 2]\n[output truncated]"
+        );
+
+        let output = ShellOutput::default();
+        assert_eq!(output.to_model_output(), "[terminated by a signal]");
+    }
+
+    #[cfg(feature = "local-shell")]
+    #[tokio::test]
+    async fn local_executor_runs_allowed_commands() {
+        let executor = LocalShellExecutor::new(["echo"]);
+
+        let output = executor.exec(&exec(&["echo", "hello"])).await.unwrap();
+        assert_eq!(output.stdout, "hello\n");
+        assert_eq!(output.exit_code, Some(0));
+        assert!(!output.truncated);
+
+        let error = executor.exec(&exec(&["rm", "-rf", "/"])).await.unwrap_err();
+        assert!(matches!(error, ShellError::NotAllowed(command) if command == "rm"));
+        assert!(matches!(
+            executor.exec(&exec(&[])).await.unwrap_err(),
+            ShellError::EmptyCommand
+        ));
+    }
+
+    #[cfg(feature = "local-shell")]
+    #[tokio::test]
+    async fn local_executor_caps_output_and_times_out() {
+        let executor = LocalShellExecutor::new(["head", "sleep"]).max_output_bytes(16);
+
+        let output = executor
+            .exec(&exec(&["head", "-c", "100000", "/dev/zero"]))
+            .await
+            .unwrap();
+        assert_eq!(output.stdout.len(), 16);
+        assert!(output.truncated);
+
+        let action = LocalShellExecAction {
+            timeout_ms: Some(50),
+            ..exec(&["sleep", "5"])
+        };
+        let error = executor.exec(&action).await.unwrap_err();
+        assert!(
+            matches!(error, ShellError::Timeout(timeout) if timeout == Duration::from_millis(50))
+        );
+    }
+
+    #[cfg(feature = "local-shell")]
+    #[tokio::test]
+    async fn local_executor_clears_env_and_only_allows_listed_variables() {
+        let executor = LocalShellExecutor::new(["echo", "env"]).allowed_env(["GREETING"]);
+
+        let action = LocalShellExecAction {
+            env: [("GREETING".to_string(), "hi".to_string())].into(),
+            ..exec(&["env"])
+        };
+        let output = executor.exec(&action).await.unwrap();
+        assert_eq!(output.stdout, "GREETING=hi\n");
+
+        for name in [
+            "PATH",
+            "LD_PRELOAD",
+            "LD_LIBRARY_PATH",
+            "DYLD_INSERT_LIBRARIES",
+            "GIT_SSH_COMMAND",
+            "BASH_ENV",
+        ] {
+            let action = LocalShellExecAction {
+                env: [(name.to_string(), "/tmp/evil".to_string())].into(),
+                ..exec(&["echo", "hello"])
+            };
+
+            let error = executor.exec(&action).await.unwrap_err();
+            assert!(matches!(error, ShellError::EnvNotAllowed(variable) if variable == name));
+        }
+    }
+
+    #[cfg(feature = "local-shell")]
+    fn exec(command: &[&str]) -> LocalShellExecAction {
+        LocalShellExecAction {
+            command: command.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        }
+    }
+}
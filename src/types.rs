@@ -1,4 +1,5 @@
 pub mod config;
+#[macro_use]
 mod helpers;
 pub mod item;
 pub mod request;
@@ -15,6 +16,8 @@ pub use response::*;
 pub use stream::*;
 pub use tools::*;
 
+pub(crate) use helpers::query_value;
+
 /// The model to use for generating a response.
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
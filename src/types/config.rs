@@ -35,12 +35,14 @@ pub enum TextFormat {
         /// The schema for the response format, described as a JSON Schema object. Learn how to build JSON schemas [here](https://json-schema.org/).
         schema: serde_json::Value,
         /// A description of what the response format is for, used by the model to determine how to respond in the format.
+        #[serde(default, skip_serializing_if = "String::is_empty")]
         description: String,
         /// The name of the response format. Must be a-z, A-Z, 0-9, or contain underscores and dashes, with a maximum length of 64.
         name: String,
         /// Whether to enable strict schema adherence when generating the output.
         /// If set to true, the model will always follow the exact schema defined in the schema field. Only a subset of JSON Schema is supported when strict is true.
         /// To learn more, read the [Structured Outputs guide](https://platform.openai.com/docs/guides/structured-outputs).
+        #[serde(skip_serializing_if = "Option::is_none")]
         strict: Option<bool>,
     },
     /// JSON object response format. An older method of generating JSON responses.
@@ -49,6 +51,29 @@ pub enum TextFormat {
     JsonObject,
 }
 
+impl TextFormat {
+    /// A JSON schema response format generated from `T`, with strict mode enabled.
+    ///
+    /// The schema is adjusted for [strict mode](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas) the same way as
+    /// [`Tool::function`](crate::types::Tool::function). Use [`Client::create_parsed`](crate::Client::create_parsed) to also parse the output.
+    #[cfg(feature = "schemars")]
+    #[must_use]
+    pub fn json_schema<T: schemars::JsonSchema>() -> Self {
+        let schema = crate::schema::strict_schema_for::<T>();
+
+        Self::JsonSchema {
+            strict: Some(true),
+            name: crate::schema::format_name(&T::schema_name()),
+            description: schema
+                .get("description")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            schema,
+        }
+    }
+}
+
 /// Configuration options for [reasoning models](https://platform.openai.com/docs/guides/reasoning).
 /// Only available for o-series models.
 #[derive(Debug, Clone, Default, Builder, Serialize, Deserialize)]
@@ -66,6 +91,7 @@ pub struct ReasoningConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ReasoningEffort {
+    Minimal,
     Low,
     High,
     Medium,
@@ -75,6 +101,7 @@ pub enum ReasoningEffort {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SummaryConfig {
+    Auto,
     Concise,
     Detailed,
 }
@@ -86,5 +113,7 @@ pub enum ServiceTier {
     #[default]
     Auto,
     Flex,
+    Scale,
     Default,
+    Priority,
 }
@@ -1,4 +1,13 @@
-use super::{ContentInput, ContentItem, Input, InputItem, InputListItem, InputMessage, Model};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, value::MapDeserializer},
+    ser::{Error as _, SerializeMap},
+};
+use std::collections::HashMap;
+
+use super::{
+    ContentInput, ContentItem, Input, InputItem, InputListItem, InputMessage, Model, OutputItem,
+};
 
 macro_rules! string_variant {
     ($name:ident, $variant:ident) => {
@@ -41,6 +50,32 @@ impl From<InputItem> for InputListItem {
     }
 }
 
+impl From<OutputItem> for InputItem {
+    fn from(value: OutputItem) -> Self {
+        match value {
+            OutputItem::Message(message) => Self::OutputMessage(message),
+            OutputItem::FileSearch(call) => Self::FileSearch(call),
+            OutputItem::FunctionCall(call) => Self::FunctionCall(call),
+            OutputItem::WebSearchResults(call) => Self::WebSearchResults(call),
+            OutputItem::ComputerToolCall(call) => Self::ComputerToolCall(call),
+            OutputItem::ImageGeneration(call) => Self::ImageGeneration(call),
+            OutputItem::CustomToolCall(call) => Self::CustomToolCall(call),
+            OutputItem::Reasoning(reasoning) => Self::Reasoning(reasoning),
+            OutputItem::CodeInterpreterCall(call) => Self::CodeInterpreterCall(call),
+            OutputItem::LocalShellCall(call) => Self::LocalShellCall(call),
+            OutputItem::McpListTools(list) => Self::McpListTools(list),
+            OutputItem::McpCall(call) => Self::McpCall(call),
+            OutputItem::McpApprovalRequest(request) => Self::McpApprovalRequest(request),
+            OutputItem::Unknown { r#type, raw } => Self::Unknown { r#type, raw },
+        }
+    }
+}
+impl From<OutputItem> for InputListItem {
+    fn from(value: OutputItem) -> Self {
+        Self::Item(value.into())
+    }
+}
+
 string_variant!(Input, Text);
 string_variant!(ContentInput, Text);
 string_variant_var!(ContentItem, Text, text);
@@ -68,3 +103,157 @@ impl From<&str> for Model {
         Self::from(s.to_string())
     }
 }
+
+/// The string a fieldless enum variant is serialized as, for use as a query parameter.
+pub fn query_value<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        _ => unreachable!("query parameter values serialize to strings"),
+    }
+}
+
+/// Serializes a config type that responses share with requests, leaving out its unset fields.
+///
+/// Responses echo these types back with explicit `null`s, so their fields keep them; requests omit them instead.
+pub fn serialize_without_nulls<T: Serialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut value = serde_json::to_value(value).map_err(S::Error::custom)?;
+    if let serde_json::Value::Object(map) = &mut value {
+        map.retain(|_, value| !value.is_null());
+    }
+
+    value.serialize(serializer)
+}
+
+/// Serializes prompt variables, sending text values as plain strings.
+pub fn serialize_prompt_variables<S: Serializer>(
+    variables: &HashMap<String, ContentItem>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(variables.len()))?;
+    for (name, value) in variables {
+        match value {
+            ContentItem::Text { text } => map.serialize_entry(name, text)?,
+            value => map.serialize_entry(name, value)?,
+        }
+    }
+    map.end()
+}
+
+/// Deserializes prompt variables, accepting either plain strings or input content, and `null` for no variables.
+pub fn deserialize_prompt_variables<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<String, ContentItem>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Variable {
+        Text(String),
+        Content(ContentItem),
+    }
+
+    let variables = Option::<HashMap<String, Variable>>::deserialize(deserializer)?;
+
+    Ok(variables
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, value)| match value {
+            Variable::Text(text) => (name, ContentItem::Text { text }),
+            Variable::Content(content) => (name, content),
+        })
+        .collect())
+}
+
+/// Implements `Serialize` and `Deserialize` for an internally tagged enum with an `Unknown { r#type, raw }` variant.
+///
+/// The enum derives both traits with `#[serde(remote = "Self")]`, and its `Unknown` variant is skipped.
+/// Values with a `type` the enum doesn't know are deserialized into `Unknown` and serialized back unchanged,
+/// while values of a known `type` that fail to parse are errors.
+macro_rules! tagged_with_unknown {
+    ($name:ident) => {
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    Self::Unknown { raw, .. } => raw.serialize(serializer),
+                    known => Self::serialize(known, serializer),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                super::helpers::deserialize_tagged(
+                    deserializer,
+                    |probe| Self::deserialize(probe).map(drop),
+                    |raw| Self::deserialize(raw),
+                    |r#type, raw| Self::Unknown { r#type, raw },
+                )
+            }
+        }
+    };
+}
+
+/// Deserializes a tagged enum, using `unknown` only when `probe` reports that its `type` isn't one of the enum's variants.
+pub fn deserialize_tagged<'de, T, D: Deserializer<'de>>(
+    deserializer: D,
+    probe: impl FnOnce(TagProbe<'_>) -> Result<(), TagError>,
+    known: impl FnOnce(serde_json::Value) -> Result<T, serde_json::Error>,
+    unknown: impl FnOnce(String, serde_json::Value) -> T,
+) -> Result<T, D::Error> {
+    let raw = serde_json::Value::deserialize(deserializer)?;
+    let r#type = raw
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| D::Error::missing_field("type"))?
+        .to_string();
+
+    // Deserializing just the tag fails with an unknown variant error only if the enum has no variant for it.
+    let tag = MapDeserializer::new(std::iter::once(("type", r#type.as_str())));
+    let is_unknown = matches!(
+        probe(tag),
+        Err(TagError {
+            unknown_variant: true
+        })
+    );
+    if is_unknown {
+        return Ok(unknown(r#type, raw));
+    }
+
+    known(raw).map_err(D::Error::custom)
+}
+
+/// A deserializer containing only the `type` of a tagged value.
+pub type TagProbe<'a> = MapDeserializer<'a, std::iter::Once<(&'a str, &'a str)>, TagError>;
+
+/// The error returned when deserializing a [`TagProbe`], recording whether the tag was an unknown variant.
+#[derive(Debug)]
+pub struct TagError {
+    unknown_variant: bool,
+}
+
+impl std::fmt::Display for TagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.unknown_variant {
+            "unknown variant"
+        } else {
+            "invalid value"
+        })
+    }
+}
+
+impl std::error::Error for TagError {}
+
+impl Error for TagError {
+    fn custom<T: std::fmt::Display>(_msg: T) -> Self {
+        Self {
+            unknown_variant: false,
+        }
+    }
+
+    fn unknown_variant(_variant: &str, _expected: &'static [&'static str]) -> Self {
+        Self {
+            unknown_variant: true,
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use super::{APIInputMessage, Role};
-use serde::{Deserialize, Serialize};
+use crate::ArgumentsError;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use std::collections::HashMap;
 
 /// Content items generated by the model.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum OutputItem {
     /// An output message from the model.
     Message(OutputMessage),
@@ -22,15 +23,40 @@ pub enum OutputItem {
     /// A tool call to generate an image. See the [image generation guide](https://platform.openai.com/docs/guides/image-generation) for more information.
     #[serde(rename = "image_generation_call")]
     ImageGeneration(ImageGenerationCall),
+    /// A call to a custom tool created by the model. See the [custom tools guide](https://platform.openai.com/docs/guides/function-calling#custom-tools) for more information.
+    CustomToolCall(CustomToolCall),
     /// A description of the chain of thought used by a reasoning model while generating a response.
     Reasoning(Reasoning),
+    /// A tool call to run code. See the [code interpreter guide](https://platform.openai.com/docs/guides/tools-code-interpreter) for more information.
+    #[serde(rename = "code_interpreter_call")]
+    CodeInterpreterCall(CodeInterpreterCall),
+    /// A tool call to run a command on the local shell. See the [local shell guide](https://platform.openai.com/docs/guides/tools-local-shell) for more information.
+    LocalShellCall(LocalShellCall),
+    /// A list of tools available on an MCP server.
+    McpListTools(McpListTools),
+    /// An invocation of a tool on an MCP server. See the [MCP guide](https://platform.openai.com/docs/guides/tools-remote-mcp) for more information.
+    McpCall(McpCall),
+    /// A request for human approval of a tool invocation. Answer it with [`McpApprovalRequest::approve`] or [`McpApprovalRequest::deny`].
+    McpApprovalRequest(McpApprovalRequest),
+    /// An output item of a type this crate doesn't know about yet.
+    ///
+    /// The original JSON is kept in `raw`, and serialized back unchanged.
+    #[serde(skip)]
+    Unknown {
+        /// The `type` of the output item.
+        r#type: String,
+        /// The output item as returned by the API.
+        raw: serde_json::Value,
+    },
 }
 
+tagged_with_unknown!(OutputItem);
+
 /// An item representing part of the context for the response to be generated by the model.
 ///
 /// Can contain text, images, and audio inputs, as well as previous assistant responses and tool call outputs.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum InputItem {
     /// A message input to the model with a role indicating instruction following hierarchy.
     ///
@@ -38,7 +64,9 @@ pub enum InputItem {
     #[serde(rename = "message")]
     InputMessage(APIInputMessage),
     /// An output message from the model.
-    #[serde(rename = "message")]
+    ///
+    /// Shares the `message` type with [`InputItem::InputMessage`], so it is only ever serialized.
+    #[serde(rename = "message", skip_deserializing)]
     OutputMessage(OutputMessage),
     /// The results of a file search tool call.
     ///
@@ -67,10 +95,49 @@ pub enum InputItem {
     FunctionCall(FunctionCall),
     /// The output of a function tool call.
     FunctionCallOutput(FunctionCallOutput),
+    /// A call to a custom tool created by the model.
+    ///
+    /// See the [custom tools guide](https://platform.openai.com/docs/guides/function-calling#custom-tools) for more information.
+    CustomToolCall(CustomToolCall),
+    /// The output of a custom tool call.
+    CustomToolCallOutput(CustomToolCallOutput),
     /// A description of the chain of thought used by a reasoning model while generating a response.
     Reasoning(Reasoning),
+    /// A tool call to run code.
+    ///
+    /// See the [code interpreter guide](https://platform.openai.com/docs/guides/tools-code-interpreter) for more information.
+    #[serde(rename = "code_interpreter_call")]
+    CodeInterpreterCall(CodeInterpreterCall),
+    /// A tool call to run a command on the local shell.
+    ///
+    /// See the [local shell guide](https://platform.openai.com/docs/guides/tools-local-shell) for more information.
+    LocalShellCall(LocalShellCall),
+    /// The output of a local shell tool call.
+    LocalShellCallOutput(LocalShellCallOutput),
+    /// A list of tools available on an MCP server.
+    McpListTools(McpListTools),
+    /// An invocation of a tool on an MCP server.
+    ///
+    /// See the [MCP guide](https://platform.openai.com/docs/guides/tools-remote-mcp) for more information.
+    McpCall(McpCall),
+    /// A request for human approval of a tool invocation.
+    McpApprovalRequest(McpApprovalRequest),
+    /// A response to an MCP approval request.
+    McpApprovalResponse(McpApprovalResponse),
+    /// An input item of a type this crate doesn't know about yet.
+    ///
+    /// The original JSON is kept in `raw`, and serialized back unchanged.
+    #[serde(skip)]
+    Unknown {
+        /// The `type` of the input item.
+        r#type: String,
+        /// The input item as returned by the API.
+        raw: serde_json::Value,
+    },
 }
 
+tagged_with_unknown!(InputItem);
+
 /// An output message from the model.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputMessage {
@@ -82,11 +149,15 @@ pub struct OutputMessage {
     pub role: Role,
     /// The status of the message input.
     pub status: MessageStatus,
+    /// Fields returned by the API that this crate doesn't model, kept so the message round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The content of the output message.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum OutputContent {
     /// A text output from the model.
     #[serde(rename = "output_text")]
@@ -101,11 +172,23 @@ pub enum OutputContent {
         /// The refusal explanation from the model.
         refusal: String,
     },
+    /// A content part of a type this crate doesn't know about yet.
+    ///
+    /// The original JSON is kept in `raw`, and serialized back unchanged.
+    #[serde(skip)]
+    Unknown {
+        /// The `type` of the content part.
+        r#type: String,
+        /// The content part as returned by the API.
+        raw: serde_json::Value,
+    },
 }
 
+tagged_with_unknown!(OutputContent);
+
 /// An annotation of the text output.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum Annotation {
     /// A citation to a file.
     FileCitation {
@@ -133,8 +216,33 @@ pub enum Annotation {
         /// The index of the file in the list of files.
         index: u64,
     },
+    /// A citation for a container file used to generate a model response.
+    ContainerFileCitation {
+        /// The ID of the container file.
+        container_id: String,
+        /// The index of the last character of the container file citation in the message.
+        end_index: u64,
+        /// The ID of the file.
+        file_id: String,
+        /// The filename of the container file cited.
+        filename: String,
+        /// The index of the first character of the container file citation in the message.
+        start_index: u64,
+    },
+    /// An annotation of a type this crate doesn't know about yet.
+    ///
+    /// The original JSON is kept in `raw`, and serialized back unchanged.
+    #[serde(skip)]
+    Unknown {
+        /// The `type` of the annotation.
+        r#type: String,
+        /// The annotation as returned by the API.
+        raw: serde_json::Value,
+    },
 }
 
+tagged_with_unknown!(Annotation);
+
 /// The status of a message.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -157,6 +265,10 @@ pub struct FileSearchCall {
     pub status: FileSearchStatus,
     /// The results of the file search tool call.
     pub results: Option<Vec<FileSearchResult>>,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A result of the file search tool call.
@@ -207,6 +319,25 @@ pub struct FunctionCall {
     pub name: String,
     /// The status of the item.
     pub status: Option<FunctionCallStatus>,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FunctionCall {
+    /// Deserializes the arguments the model generated for this call.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the arguments aren't valid JSON, or don't match `Args`. See [`ArgumentsError`].
+    pub fn parse_arguments<Args: DeserializeOwned>(&self) -> Result<Args, ArgumentsError> {
+        serde_json::from_str(&self.arguments).map_err(|source| ArgumentsError {
+            source,
+            name: self.name.clone(),
+            call_id: self.call_id.clone(),
+        })
+    }
 }
 
 /// The status of the function call.
@@ -226,13 +357,57 @@ pub enum FunctionCallStatus {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionCallOutput {
     /// The unique ID of the function tool call output. Populated when this item is returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     /// The status of the item. Populated when items are returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<FunctionCallStatus>,
     /// The ID of the computer tool call that produced the output.
     pub call_id: String,
     /// A JSON string of the output of the function tool call.
     pub output: String,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A call to a custom tool created by the model.
+///
+/// See the [custom tools guide](https://platform.openai.com/docs/guides/function-calling#custom-tools) for more information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomToolCall {
+    /// The unique ID of the custom tool call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// An identifier used to map this custom tool call to a tool call output.
+    pub call_id: String,
+    /// The name of the custom tool being called.
+    pub name: String,
+    /// The input for the custom tool call generated by the model.
+    pub input: String,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The output of a custom tool call.
+///
+/// See the [custom tools guide](https://platform.openai.com/docs/guides/function-calling#custom-tools) for more information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomToolCallOutput {
+    /// The unique ID of the custom tool call output. Populated when this item is returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The call ID, used to map this custom tool call output to a custom tool call.
+    pub call_id: String,
+    /// The output from the custom tool call generated by your code.
+    pub output: String,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tool call to run a web search.
@@ -244,6 +419,10 @@ pub struct WebSearchCall {
     pub id: String,
     /// The status of the web search tool call.
     pub status: String,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tool call to run a computer action.
@@ -259,6 +438,10 @@ pub struct ComputerToolCall {
     pub pending_safety_checks: Vec<SafetyCheck>,
     /// The status of the item.
     pub status: ComputerCallStatus,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The action of the computer call.
@@ -368,15 +551,22 @@ pub enum ComputerCallStatus {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComputerToolCallOutput {
     /// The ID of the computer tool call output. Populated when this item is returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     /// The status of the item. Populated when items are returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<ComputerCallStatus>,
     /// The ID of the computer tool call that produced the output.
     pub call_id: String,
     /// A computer screenshot image used with the computer use tool.
     pub output: ComputerCallOutput,
     /// The safety checks reported by the API that have been acknowledged by the developer.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub acknowledged_safety_checks: Option<Vec<SafetyCheck>>,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A computer screenshot image used with the computer use tool.
@@ -387,15 +577,16 @@ pub enum ComputerCallOutput {
     #[serde(rename = "computer_screenshot")]
     Screenshot {
         /// The identifier of an uploaded file that contains the screenshot.
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_id: Option<String>,
         /// The URL of the screenshot image.
+        #[serde(skip_serializing_if = "Option::is_none")]
         image_url: Option<String>,
     },
 }
 
 /// A tool call to generate an image.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
 pub struct ImageGenerationCall {
     /// The unique ID of the image generation tool call.
     pub id: String,
@@ -403,6 +594,10 @@ pub struct ImageGenerationCall {
     pub result: Option<String>,
     /// The status of the image generation tool call.
     pub status: ImageGenerationStatus,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The status of the image generation tool call.
@@ -417,6 +612,255 @@ pub enum ImageGenerationStatus {
     Incomplete,
 }
 
+/// A tool call to run code.
+///
+/// See the [code interpreter guide](https://platform.openai.com/docs/guides/tools-code-interpreter) for more information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeInterpreterCall {
+    /// The unique ID of the code interpreter tool call.
+    pub id: String,
+    /// The code to run, or `None` if not available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The ID of the container used to run the code.
+    pub container_id: String,
+    /// The outputs generated by the code interpreter, such as logs or images. Only populated when `Include::CodeInterpreterCallOutputs` is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outputs: Option<Vec<CodeInterpreterOutput>>,
+    /// The status of the code interpreter tool call.
+    pub status: CodeInterpreterCallStatus,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An output generated by the code interpreter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CodeInterpreterOutput {
+    /// The logs output from the code interpreter.
+    Logs {
+        /// The logs output from the code interpreter.
+        logs: String,
+    },
+    /// An image output from the code interpreter.
+    Image {
+        /// The URL of the image output from the code interpreter.
+        url: String,
+    },
+}
+
+/// The status of the code interpreter tool call.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeInterpreterCallStatus {
+    InProgress,
+    Completed,
+    Incomplete,
+    Interpreting,
+    Failed,
+}
+
+/// A tool call to run a command on the local shell.
+///
+/// See the [local shell guide](https://platform.openai.com/docs/guides/tools-local-shell) for more information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalShellCall {
+    /// The unique ID of the local shell call.
+    pub id: String,
+    /// The unique ID of the local shell tool call generated by the model.
+    pub call_id: String,
+    /// The command to run.
+    pub action: LocalShellAction,
+    /// The status of the local shell call.
+    pub status: LocalShellCallStatus,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The command requested by a local shell call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LocalShellAction {
+    /// Execute a shell command on the server.
+    Exec(LocalShellExecAction),
+}
+
+/// Execute a shell command on the server.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocalShellExecAction {
+    /// The command to run, as a list of arguments.
+    pub command: Vec<String>,
+    /// Environment variables to set for the command.
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Optional timeout in milliseconds for the command.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    /// Optional user to run the command as.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Optional working directory to run the command in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_directory: Option<String>,
+}
+
+/// The status of the local shell call.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalShellCallStatus {
+    InProgress,
+    Completed,
+    Incomplete,
+}
+
+/// The output of a local shell tool call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalShellCallOutput {
+    /// The unique ID of the local shell tool call output. Populated when this item is returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the local shell tool call that produced the output.
+    pub call_id: String,
+    /// The output of the command, including both stdout and stderr.
+    pub output: String,
+    /// The status of the item. Populated when items are returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<LocalShellCallStatus>,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A list of tools available on an MCP server.
+///
+/// See the [MCP guide](https://platform.openai.com/docs/guides/tools-remote-mcp) for more information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpListTools {
+    /// The unique ID of the list.
+    pub id: String,
+    /// The label of the MCP server.
+    pub server_label: String,
+    /// The tools available on the server.
+    pub tools: Vec<McpToolInfo>,
+    /// The error message if the server could not list tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A tool available on an MCP server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpToolInfo {
+    /// The name of the tool.
+    pub name: String,
+    /// The JSON schema describing the tool's input.
+    pub input_schema: serde_json::Value,
+    /// The description of the tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Additional annotations about the tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<serde_json::Value>,
+}
+
+/// An invocation of a tool on an MCP server.
+///
+/// See the [MCP guide](https://platform.openai.com/docs/guides/tools-remote-mcp) for more information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCall {
+    /// The unique ID of the tool call.
+    pub id: String,
+    /// The label of the MCP server running the tool.
+    pub server_label: String,
+    /// The name of the tool that was run.
+    pub name: String,
+    /// A JSON string of the arguments passed to the tool.
+    pub arguments: String,
+    /// The output from the tool call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// The error from the tool call, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The ID of the approval request this call was approved by, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_request_id: Option<String>,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A request for human approval of a tool invocation.
+///
+/// See the [MCP guide](https://platform.openai.com/docs/guides/tools-remote-mcp#approvals) for more information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpApprovalRequest {
+    /// The unique ID of the approval request.
+    pub id: String,
+    /// The label of the MCP server making the request.
+    pub server_label: String,
+    /// The name of the tool to run.
+    pub name: String,
+    /// A JSON string of arguments for the tool.
+    pub arguments: String,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl McpApprovalRequest {
+    /// An input item approving this request, to send in the next request.
+    #[must_use]
+    pub fn approve(&self) -> InputItem {
+        self.respond(true, None)
+    }
+
+    /// An input item denying this request, with an optional reason, to send in the next request.
+    #[must_use]
+    pub fn deny(&self, reason: Option<String>) -> InputItem {
+        self.respond(false, reason)
+    }
+
+    fn respond(&self, approve: bool, reason: Option<String>) -> InputItem {
+        InputItem::McpApprovalResponse(McpApprovalResponse {
+            id: None,
+            approve,
+            reason,
+            approval_request_id: self.id.clone(),
+            extra: serde_json::Map::new(),
+        })
+    }
+}
+
+/// A response to an MCP approval request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpApprovalResponse {
+    /// The unique ID of the approval response. Populated when this item is returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the approval request being answered.
+    pub approval_request_id: String,
+    /// Whether the request was approved.
+    pub approve: bool,
+    /// The reason for the decision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
 /// A description of the chain of thought used by a reasoning model while generating a response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Reasoning {
@@ -424,8 +868,17 @@ pub struct Reasoning {
     pub id: String,
     /// Reasoning text contents.
     pub summary: Vec<ReasoningSummary>,
-    /// The status of the item.
-    pub status: ReasoningStatus,
+    /// The encrypted content of the reasoning item. Populated when a response is generated with
+    /// [`Include::ReasoningEncryptedContent`](crate::types::Include::ReasoningEncryptedContent).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_content: Option<String>,
+    /// The status of the item. Populated when items are returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ReasoningStatus>,
+    /// Fields returned by the API that this crate doesn't model, kept so the item round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Reasoning text contents.
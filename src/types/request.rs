@@ -3,9 +3,14 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::{
-    InputItem, MessageStatus, Model, ReasoningConfig, Role, ServiceTier, TextConfig, Tool,
-    ToolChoice, Truncation,
+    InputItem, MessageStatus, Model, ReasoningConfig, Role, ServiceTier, TextConfig, TextFormat,
+    Tool, ToolChoice, Truncation,
+    helpers::{
+        deserialize_prompt_variables, query_value, serialize_prompt_variables,
+        serialize_without_nulls,
+    },
 };
+use crate::schema::{self, SchemaError, SchemaLimits};
 
 /// The Request object.
 #[derive(Debug, Clone, Builder, Serialize, Deserialize)]
@@ -15,55 +20,84 @@ pub struct Request {
     pub model: Model,
     /// Text, image, or file inputs to the model, used to generate a response.
     pub input: Input,
+    /// Whether to run the model response in the background.
+    /// Background responses are created immediately with a `queued` status. Use [`Client::wait_for_completion`](crate::Client::wait_for_completion) to poll them until they finish.
+    /// Learn more about [background mode](https://platform.openai.com/docs/guides/background).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<bool>,
     /// Specify additional output data to include in the model response.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub include: Option<Vec<Include>>,
     /// Inserts a system (or developer) message as the first item in the model's context.
     /// When using along with `previous_response_id`, the instructions from a previous response will be not be carried over to the next response. This makes it simple to swap out system (or developer) messages in new responses.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub instructions: Option<String>,
     /// An upper bound for the number of tokens that can be generated for a response, including visible output tokens and [reasoning tokens](https://platform.openai.com/docs/guides/reasoning).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_output_tokens: Option<u64>,
     /// Set of 16 key-value pairs that can be attached to an object. This can be useful for storing additional information about the object in a structured format, and querying for objects via API or the dashboard.
     /// Keys are strings with a maximum length of 64 characters. Values are strings with a maximum length of 512 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
     /// Whether to allow the model to run tool calls in parallel.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parallel_tool_calls: Option<bool>,
     /// The unique ID of the previous response to the model. Use this to create multi-turn conversations.
     /// Learn more about [conversation state](https://platform.openai.com/docs/guides/conversation-state).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_response_id: Option<String>,
+    /// Reference to a prompt template and its variables.
+    /// Learn more about [reusable prompts](https://platform.openai.com/docs/guides/text?api-mode=responses#reusable-prompts).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<Prompt>,
     /// Configuration options for [reasoning models](https://platform.openai.com/docs/guides/reasoning).
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_without_nulls"
+    )]
     pub reasoning: Option<ReasoningConfig>,
     /// Specifies the latency tier to use for processing the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub service_tier: Option<ServiceTier>,
     /// Whether to store the generated model response for later retrieval via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub store: Option<bool>,
     /// If set to true, the model response data will be streamed to the client as it is generated using [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events/Using_server-sent_events#event_stream_format).
     ///
     /// This flag is automatically set depending on the function you call, so it's best to not touch it.
     #[doc(hidden)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
     /// What sampling temperature to use, between 0 and 2.
     /// Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
     /// We generally recommend altering this or `top_p` but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
     /// Configuration options for a text response from the model. Can be plain text or structured JSON data. Learn more:
     /// - [Text inputs and outputs](https://platform.openai.com/docs/guides/text)
     /// - [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<TextConfig>,
     /// How the model should select which tool (or tools) to use when generating a response.
     ///
     /// See the `tools` parameter to see how to specify which tools the model can call.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_choice: Option<ToolChoice>,
     /// An array of tools the model may call while generating a response. You can specify which tool to use by setting the `tool_choice` parameter.
     /// The two categories of tools you can provide the model are:
     /// - **Built-in tools**: Tools that are provided by OpenAI that extend the model's capabilities, like [web search](https://platform.openai.com/docs/guides/tools-web-search) or [file search](https://platform.openai.com/docs/guides/tools-file-search). Learn more about [built-in tools](https://platform.openai.com/docs/guides/tools).
     /// - **Function calls (custom tools)**: Functions that are defined by you, enabling the model to call your own code. Learn more about [function calling](https://platform.openai.com/docs/guides/function-calling).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<Vec<Tool>>,
     /// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with `top_p` probability mass. So 0.1 means only the tokens comprising the top 10% probability mass are considered.
     /// We generally recommend altering this or `temperature` but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub top_p: Option<f32>,
     /// The truncation strategy to use for the model response.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub truncation: Option<Truncation>,
     /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse. [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
 
@@ -78,6 +112,7 @@ impl Default for Request {
             stream: None,
             include: None,
             metadata: None,
+            background: None,
             reasoning: None,
             truncation: None,
             tool_choice: None,
@@ -87,12 +122,58 @@ impl Default for Request {
             model: Model::GPT4o,
             max_output_tokens: None,
             parallel_tool_calls: None,
+            prompt: None,
             previous_response_id: None,
             input: Input::Text(String::new()),
         }
     }
 }
 
+impl Request {
+    /// Checks the schemas of strict function tools and of a strict structured output format against the restrictions and
+    /// limits of [strict mode](https://platform.openai.com/docs/guides/structured-outputs#supported-schemas).
+    ///
+    /// The [`Client`](crate::Client) runs this before sending a request.
+    ///
+    /// ## Errors
+    ///
+    /// Errors with the violations of every invalid schema, with JSON pointers relative to the request. See [`SchemaError`].
+    pub fn validate_strict_schemas(&self, limits: &SchemaLimits) -> Result<(), SchemaError> {
+        let mut violations = Vec::new();
+
+        for (index, tool) in self.tools.iter().flatten().enumerate() {
+            if let Tool::Function {
+                parameters,
+                strict: true,
+                ..
+            } = tool
+                && let Err(error) = schema::validate(parameters, limits)
+            {
+                violations.extend(error.nest(&format!("/tools/{index}/parameters")).violations);
+            }
+        }
+
+        if let Some(TextConfig {
+            format:
+                TextFormat::JsonSchema {
+                    schema,
+                    strict: Some(true),
+                    ..
+                },
+        }) = &self.text
+            && let Err(error) = schema::validate(schema, limits)
+        {
+            violations.extend(error.nest("/text/format/schema").violations);
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError { violations })
+        }
+    }
+}
+
 /// Text, image, or file inputs to the model, used to generate a response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -151,7 +232,12 @@ pub struct APIInputMessage {
     /// Text, image, or audio input to the model, used to generate a response. Can also contain previous assistant responses.
     pub content: ContentItem,
     /// The status of the message. Populated when the message is returned via API.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<MessageStatus>,
+    /// Fields returned by the API that this crate doesn't model, kept so the message round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Text, image, or audio input to the model, used to generate a response. Can also contain previous assistant responses.
@@ -167,22 +253,87 @@ pub enum ContentItem {
         /// The detail level of the image to be sent to the model.
         detail: ImageDetail,
         /// The ID of the file to be sent to the model.
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_id: Option<String>,
         /// The URL of the image to be sent to the model. A fully qualified URL or base64 encoded image in a data URL.
+        #[serde(skip_serializing_if = "Option::is_none")]
         image_url: Option<String>,
     },
     /// A file input to the model.
     #[serde(rename = "input_file")]
     File {
         /// The content of the file to be sent to the model.
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_data: Option<String>,
         /// The ID of the file to be sent to the model.
+        #[serde(skip_serializing_if = "Option::is_none")]
         file_id: Option<String>,
         /// The name of the file to be sent to the model.
+        #[serde(skip_serializing_if = "Option::is_none")]
         filename: Option<String>,
     },
 }
 
+/// Reference to a prompt template and its variables.
+///
+/// Learn more about [reusable prompts](https://platform.openai.com/docs/guides/text?api-mode=responses#reusable-prompts).
+///
+/// # Examples
+/// ```rust
+/// use openai_responses::types::{ContentItem, ImageDetail, Prompt};
+///
+/// let prompt = Prompt::new("pmpt_123")
+///     .version("2")
+///     .variable("customer_name", "Jane Doe")
+///     .variable("product_photo", ContentItem::Image {
+///         detail: ImageDetail::Auto,
+///         file_id: Some("file-abc123".to_string()),
+///         image_url: None,
+///     });
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Prompt {
+    /// The unique identifier of the prompt template to use.
+    pub id: String,
+    /// Optional version of the prompt template. Defaults to the current version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Values to substitute in for variables in the prompt. Text values are sent as plain strings.
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_prompt_variables",
+        deserialize_with = "deserialize_prompt_variables"
+    )]
+    pub variables: HashMap<String, ContentItem>,
+}
+
+impl Prompt {
+    /// Creates a reference to the current version of the prompt template with the given ID.
+    #[must_use]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            version: None,
+            variables: HashMap::new(),
+        }
+    }
+
+    /// Sets the version of the prompt template to use.
+    #[must_use]
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Sets the value substituted in for the variable `name`. Accepts strings, images and files.
+    #[must_use]
+    pub fn variable(mut self, name: impl Into<String>, value: impl Into<ContentItem>) -> Self {
+        self.variables.insert(name.into(), value.into());
+        self
+    }
+}
+
 /// The detail level of the image sent to the model.
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -206,4 +357,341 @@ pub enum Include {
     /// Include image urls from the computer call output.
     #[serde(rename = "computer_call_output.output.image_url")]
     ComputerCallImageURLs,
+    /// Include the outputs of python code execution in code interpreter tool call items.
+    #[serde(rename = "code_interpreter_call.outputs")]
+    CodeInterpreterCallOutputs,
+    /// Include an encrypted version of reasoning tokens in reasoning item outputs.
+    ///
+    /// This enables reasoning items to be used in multi-turn conversations when using the Responses API statelessly, like when the `store` parameter is set to `false`, or when an organization is enrolled in the zero data retention program.
+    #[serde(rename = "reasoning.encrypted_content")]
+    ReasoningEncryptedContent,
+}
+
+/// Query parameters for listing the input items of a response.
+#[derive(Debug, Clone, Default, Builder)]
+pub struct InputItemListParams {
+    /// An item ID to list items after, used in pagination.
+    pub after: Option<String>,
+    /// An item ID to list items before, used in pagination.
+    pub before: Option<String>,
+    /// Additional fields to include in the response.
+    pub include: Option<Vec<Include>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 20.
+    pub limit: Option<u8>,
+    /// The order to return the input items in. Default is `Desc`.
+    pub order: Option<Order>,
+}
+
+impl InputItemListParams {
+    /// The query string parameters for these params.
+    pub(crate) fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+
+        if let Some(after) = &self.after {
+            query.push(("after", after.clone()));
+        }
+        if let Some(before) = &self.before {
+            query.push(("before", before.clone()));
+        }
+        for include in self.include.iter().flatten() {
+            query.push(("include[]", query_value(include)));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(order) = self.order {
+            query.push(("order", query_value(&order)));
+        }
+
+        query
+    }
+}
+
+/// The order to return a list of items in.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Order {
+    /// Return items in ascending order.
+    Asc,
+    /// Return items in descending order.
+    #[default]
+    Desc,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        FunctionCallOutput, ImageDetail, ReasoningEffort, SearchContextSize, UserLocation,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn default_request_omits_unset_fields() {
+        assert_eq!(
+            serde_json::to_value(Request::default()).unwrap(),
+            json!({ "model": "gpt-4o", "input": "" })
+        );
+    }
+
+    #[test]
+    fn request_serializes_only_set_fields() {
+        let request = Request {
+            input: Input::List(vec![
+                InputListItem::Message(InputMessage {
+                    role: Role::User,
+                    content: ContentInput::List(vec![
+                        ContentItem::Text {
+                            text: "What's in this image?".to_string(),
+                        },
+                        ContentItem::Image {
+                            detail: ImageDetail::Auto,
+                            file_id: None,
+                            image_url: Some("https://example.com/cat.png".to_string()),
+                        },
+                    ]),
+                }),
+                InputListItem::Item(InputItem::FunctionCallOutput(FunctionCallOutput {
+                    id: None,
+                    status: None,
+                    call_id: "call_123".to_string(),
+                    output: "{}".to_string(),
+                    extra: serde_json::Map::new(),
+                })),
+            ]),
+            tools: Some(vec![
+                Tool::Function {
+                    name: "get_weather".to_string(),
+                    parameters: json!({ "type": "object" }),
+                    strict: true,
+                    description: None,
+                },
+                Tool::WebSearch {
+                    search_context_size: SearchContextSize::Low,
+                    user_location: Some(UserLocation {
+                        country: Some("US".to_string()),
+                        ..Default::default()
+                    }),
+                },
+                Tool::FileSearch {
+                    vector_store_ids: vec!["vs_123".to_string()],
+                    filters: None,
+                    max_num_results: None,
+                    ranking_options: None,
+                },
+            ]),
+            reasoning: Some(ReasoningConfig {
+                effort: Some(ReasoningEffort::Low),
+                generate_summary: None,
+            }),
+            include: Some(vec![Include::FileSearchResults]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "model": "gpt-4o",
+                "input": [
+                    {
+                        "type": "message",
+                        "role": "user",
+                        "content": [
+                            { "type": "input_text", "text": "What's in this image?" },
+                            { "type": "input_image", "detail": "auto", "image_url": "https://example.com/cat.png" },
+                        ],
+                    },
+                    { "type": "function_call_output", "call_id": "call_123", "output": "{}" },
+                ],
+                "include": ["file_search_call.results"],
+                "reasoning": { "effort": "low" },
+                "tools": [
+                    {
+                        "type": "function",
+                        "name": "get_weather",
+                        "parameters": { "type": "object" },
+                        "strict": true,
+                    },
+                    {
+                        "type": "web_search_preview",
+                        "search_context_size": "low",
+                        "user_location": { "type": "approximate", "country": "US" },
+                    },
+                    { "type": "file_search", "vector_store_ids": ["vs_123"] },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn code_interpreter_tool_serializes_container() {
+        use crate::types::{AutoContainer, CodeInterpreterContainer};
+
+        let tools = vec![
+            Tool::CodeInterpreter {
+                container: CodeInterpreterContainer::default(),
+            },
+            Tool::CodeInterpreter {
+                container: CodeInterpreterContainer::Auto(AutoContainer {
+                    file_ids: Some(vec!["file_123".to_string()]),
+                }),
+            },
+            Tool::CodeInterpreter {
+                container: CodeInterpreterContainer::Id("cntr_123".to_string()),
+            },
+        ];
+
+        let json = json!([
+            { "type": "code_interpreter", "container": { "type": "auto" } },
+            { "type": "code_interpreter", "container": { "type": "auto", "file_ids": ["file_123"] } },
+            { "type": "code_interpreter", "container": "cntr_123" },
+        ]);
+        assert_eq!(serde_json::to_value(&tools).unwrap(), json);
+
+        let tools: Vec<Tool> = serde_json::from_value(json).unwrap();
+        assert!(matches!(
+            &tools[2],
+            Tool::CodeInterpreter { container: CodeInterpreterContainer::Id(id) } if id == "cntr_123"
+        ));
+        assert_eq!(
+            serde_json::to_value(Include::CodeInterpreterCallOutputs).unwrap(),
+            "code_interpreter_call.outputs"
+        );
+    }
+
+    #[test]
+    fn mcp_tool_serializes_only_set_fields() {
+        use crate::types::{McpAllowedTools, McpApprovalPolicy, McpToolFilter};
+
+        let tool = Tool::Mcp {
+            server_label: "internal".to_string(),
+            server_url: "https://mcp.example.com/sse".to_string(),
+            headers: Some(HashMap::from([(
+                "Authorization".to_string(),
+                "Bearer token".to_string(),
+            )])),
+            allowed_tools: Some(McpAllowedTools::Names(vec!["search".to_string()])),
+            require_approval: Some(McpApprovalPolicy::Filter {
+                always: None,
+                never: Some(McpToolFilter {
+                    tool_names: vec!["search".to_string()],
+                }),
+            }),
+            server_description: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&tool).unwrap(),
+            json!({
+                "type": "mcp",
+                "server_label": "internal",
+                "server_url": "https://mcp.example.com/sse",
+                "headers": { "Authorization": "Bearer token" },
+                "allowed_tools": ["search"],
+                "require_approval": { "never": { "tool_names": ["search"] } },
+            })
+        );
+    }
+
+    #[test]
+    fn custom_tool_serializes_grammar_and_choice() {
+        use crate::types::{CustomToolFormat, GrammarSyntax};
+
+        let tool = Tool::Custom {
+            name: "run_sql".to_string(),
+            description: Some("Runs a read-only SQL query.".to_string()),
+            format: Some(CustomToolFormat::Grammar {
+                syntax: GrammarSyntax::Regex,
+                definition: r"^SELECT .+ FROM \w+;$".to_string(),
+            }),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&tool).unwrap(),
+            json!({
+                "type": "custom",
+                "name": "run_sql",
+                "description": "Runs a read-only SQL query.",
+                "format": { "type": "grammar", "syntax": "regex", "definition": r"^SELECT .+ FROM \w+;$" },
+            })
+        );
+
+        let choice = ToolChoice::Custom("run_sql".to_string());
+        let value = serde_json::to_value(&choice).unwrap();
+        assert_eq!(value, json!({ "type": "custom", "name": "run_sql" }));
+        assert!(matches!(
+            serde_json::from_value(value).unwrap(),
+            ToolChoice::Custom(name) if name == "run_sql"
+        ));
+    }
+
+    #[test]
+    fn prompt_serializes_variables() {
+        let request = Request::builder()
+            .model("gpt-4o")
+            .input("What's in the photo?")
+            .prompt(
+                Prompt::new("pmpt_123")
+                    .version("2")
+                    .variable("customer_name", "Jane Doe")
+                    .variable(
+                        "product_photo",
+                        ContentItem::Image {
+                            detail: ImageDetail::Low,
+                            file_id: Some("file-abc123".to_string()),
+                            image_url: None,
+                        },
+                    ),
+            )
+            .build();
+
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            value["prompt"],
+            json!({
+                "id": "pmpt_123",
+                "version": "2",
+                "variables": {
+                    "customer_name": "Jane Doe",
+                    "product_photo": { "type": "input_image", "detail": "low", "file_id": "file-abc123" },
+                },
+            })
+        );
+
+        let prompt: Prompt = serde_json::from_value(value["prompt"].clone()).unwrap();
+        assert!(matches!(
+            &prompt.variables["customer_name"],
+            ContentItem::Text { text } if text == "Jane Doe"
+        ));
+        assert!(matches!(
+            &prompt.variables["product_photo"],
+            ContentItem::Image { file_id: Some(id), .. } if id == "file-abc123"
+        ));
+        assert_eq!(
+            serde_json::to_value(Prompt::new("pmpt_123")).unwrap(),
+            json!({ "id": "pmpt_123" })
+        );
+    }
+
+    #[test]
+    fn list_params_encode_include_as_array() {
+        let params = InputItemListParams {
+            after: Some("item_1".to_string()),
+            include: Some(vec![Include::FileSearchResults, Include::InputImageURLs]),
+            limit: Some(50),
+            order: Some(Order::Asc),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            params.query(),
+            [
+                ("after", "item_1".to_string()),
+                ("include[]", "file_search_call.results".to_string()),
+                ("include[]", "message.input_image.image_url".to_string()),
+                ("limit", "50".to_string()),
+                ("order", "asc".to_string()),
+            ]
+        );
+    }
 }
@@ -5,7 +5,8 @@ use std::collections::HashMap;
 use crate::types::OutputContent;
 
 use super::{
-    InputItem, OutputItem, ReasoningConfig, ServiceTier, TextConfig, Tool, ToolChoice, Truncation,
+    FunctionCall, InputItem, InputListItem, OutputItem, Prompt, ReasoningConfig, ServiceTier,
+    TextConfig, Tool, ToolChoice, Truncation,
 };
 
 /// The Response object.
@@ -16,6 +17,8 @@ pub struct Response {
     pub created_at: DateTime<Utc>,
     /// Unique identifier for this Response.
     pub id: String,
+    /// Whether the response was run in the background.
+    pub background: Option<bool>,
     /// Details about why the response is incomplete.
     pub incomplete_details: Option<IncompleteDetails>,
     /// Inserts a system (or developer) message as the first item in the model's context.
@@ -34,13 +37,17 @@ pub struct Response {
     /// - Rather than accessing the first item in the `output` array and assuming it's an assistant message with the content generated by the model, you might consider using the `output_text` function.
     pub output: Vec<OutputItem>,
     /// Whether to allow the model to run tool calls in parallel.
-    pub parallel_tool_calls: bool,
+    pub parallel_tool_calls: Option<bool>,
     /// The unique ID of the previous response to the model. Use this to create multi-turn conversations.
     /// Learn more about [conversation state](https://platform.openai.com/docs/guides/conversation-state).
     pub previous_response_id: Option<String>,
+    /// Reference to a prompt template and its variables.
+    /// Learn more about [reusable prompts](https://platform.openai.com/docs/guides/text?api-mode=responses#reusable-prompts).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<Prompt>,
     /// Configuration options for [reasoning models](https://platform.openai.com/docs/guides/reasoning).
     /// Only available for o-series models.
-    pub reasoning: ReasoningConfig,
+    pub reasoning: Option<ReasoningConfig>,
     /// Specifies the latency tier to use for processing the request.
     pub service_tier: Option<ServiceTier>,
     /// The status of the response generation.
@@ -48,11 +55,11 @@ pub struct Response {
     /// What sampling temperature to use, between 0 and 2.
     /// Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
     /// We generally recommend altering this or `top_p` but not both.
-    pub temperature: f32,
+    pub temperature: Option<f32>,
     /// Configuration options for a text response from the model. Can be plain text or structured JSON data. Learn more:
     /// - [Text inputs and outputs](https://platform.openai.com/docs/guides/text)
     /// - [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs)
-    pub text: TextConfig,
+    pub text: Option<TextConfig>,
     /// How the model should select which tool (or tools) to use when generating a response.
     /// See the `tools` parameter to see how to specify which tools the model can call.
     pub tool_choice: ToolChoice,
@@ -63,7 +70,7 @@ pub struct Response {
     pub tools: Vec<Tool>,
     /// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass. So 0.1 means only the tokens comprising the top 10% probability mass are considered.
     /// We generally recommend altering this or `temperature` but not both.
-    pub top_p: f32,
+    pub top_p: Option<f32>,
     /// The truncation strategy to use for the model response.
     pub truncation: Truncation,
     /// Represents token usage details including input tokens, output tokens, a breakdown of output tokens, and the total tokens used.
@@ -72,6 +79,10 @@ pub struct Response {
     pub store: bool,
     /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse. [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
     pub user: Option<String>,
+    /// Fields returned by the API that this crate doesn't model, kept so the response round-trips unchanged. Always empty unless the `extra-fields` feature is enabled.
+    #[cfg_attr(feature = "extra-fields", serde(flatten))]
+    #[cfg_attr(not(feature = "extra-fields"), serde(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Response {
@@ -84,12 +95,31 @@ impl Response {
                 _ => None,
             })
             .flat_map(|message| &message.content)
-            .map(|content| match content {
-                OutputContent::Text { text, .. } => text.to_owned(),
-                OutputContent::Refusal { refusal } => refusal.to_owned(),
+            .filter_map(|content| match content {
+                OutputContent::Text { text, .. } => Some(text.as_str()),
+                OutputContent::Refusal { refusal } => Some(refusal.as_str()),
+                OutputContent::Unknown { .. } => None,
             })
             .collect::<String>()
     }
+
+    /// The function calls the model made while generating this response.
+    pub fn function_calls(&self) -> impl Iterator<Item = &FunctionCall> {
+        self.output.iter().filter_map(|output| match output {
+            OutputItem::FunctionCall(call) => Some(call),
+            _ => None,
+        })
+    }
+
+    /// The output of this response as input items, to send back to the model alongside the next turn's input.
+    ///
+    /// Use this instead of `previous_response_id` to continue a conversation statelessly, like when `store` is `false`.
+    /// Reasoning items are kept, and should be requested with [`Include::ReasoningEncryptedContent`](crate::types::Include::ReasoningEncryptedContent)
+    /// so reasoning models can pick up where they left off.
+    #[must_use]
+    pub fn output_as_input(&self) -> Vec<InputListItem> {
+        self.output.iter().cloned().map(Into::into).collect()
+    }
 }
 
 /// Represents token usage details including input tokens, output tokens, a breakdown of output tokens, and the total tokens used.
@@ -133,11 +163,24 @@ pub struct IncompleteDetails {
 #[serde(rename_all = "snake_case")]
 pub enum ResponseStatus {
     Failed,
+    Queued,
+    Cancelled,
     Completed,
     Incomplete,
     InProgress,
 }
 
+impl ResponseStatus {
+    /// Whether the response has finished generating, successfully or not.
+    #[must_use]
+    pub const fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            Self::Failed | Self::Cancelled | Self::Completed | Self::Incomplete
+        )
+    }
+}
+
 /// An error object returned when the model fails to generate a Response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Error {
@@ -155,28 +198,353 @@ pub struct Error {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InputItemList {
     /// A list of items used to generate this response.
-    data: Vec<InputItem>,
+    pub data: Vec<InputItem>,
     /// The ID of the first item in the list.
-    first_id: String,
+    pub first_id: Option<String>,
     /// The ID of the last item in the list.
-    last_id: String,
+    pub last_id: Option<String>,
     /// Whether there are more items available.
-    has_more: bool,
+    pub has_more: bool,
 }
 
-#[allow(clippy::redundant_pub_crate)]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub(crate) enum ResponseResult {
-    Ok(Box<Response>),
-    Err { error: Error },
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hand-written responses modelled on the shapes the API returns, one per model family.
+    const FIXTURES: [(&str, &str); 7] = [
+        (
+            "gpt-4o",
+            include_str!("../../tests/fixtures/gpt_4o_web_search.json"),
+        ),
+        (
+            "gpt-4.1",
+            include_str!("../../tests/fixtures/function_call.json"),
+        ),
+        (
+            "gpt-5",
+            include_str!("../../tests/fixtures/gpt_5_minimal_reasoning.json"),
+        ),
+        ("o3", include_str!("../../tests/fixtures/o3_reasoning.json")),
+        (
+            "o4-mini",
+            include_str!("../../tests/fixtures/o4_mini_encrypted_reasoning.json"),
+        ),
+        (
+            "o3-pro",
+            include_str!("../../tests/fixtures/o3_pro_background_queued.json"),
+        ),
+        (
+            "computer-use-preview",
+            include_str!("../../tests/fixtures/computer_use.json"),
+        ),
+    ];
+
+    #[test]
+    fn parses_every_model_family() {
+        for (family, fixture) in FIXTURES {
+            let response = serde_json::from_str::<Response>(fixture)
+                .unwrap_or_else(|error| panic!("failed to parse {family} response: {error}"));
+
+            assert!(response.model.starts_with(family), "{family}");
+        }
+    }
+
+    #[test]
+    fn accepts_null_sampling_params() {
+        let response: Response = serde_json::from_str(include_str!(
+            "../../tests/fixtures/o3_pro_background_queued.json"
+        ))
+        .unwrap();
+
+        assert_eq!(response.status, ResponseStatus::Queued);
+        assert!(response.temperature.is_none());
+        assert!(response.top_p.is_none());
+        assert!(response.usage.is_none());
+    }
+
+    #[test]
+    fn accepts_null_prompt_variables() {
+        let response: Response = serde_json::from_str(include_str!(
+            "../../tests/fixtures/prompt_without_variables.json"
+        ))
+        .unwrap();
+
+        let prompt = response.prompt.unwrap();
+        assert_eq!(prompt.version.as_deref(), Some("1"));
+        assert!(prompt.variables.is_empty());
+    }
+
+    #[test]
+    fn accepts_null_or_absent_config() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/o3_reasoning.json")).unwrap();
+        let response = json.as_object_mut().unwrap();
+        for field in [
+            "temperature",
+            "top_p",
+            "reasoning",
+            "text",
+            "parallel_tool_calls",
+        ] {
+            response.remove(field);
+        }
+
+        let response: Response = serde_json::from_value(json).unwrap();
+        assert!(response.temperature.is_none());
+        assert!(response.reasoning.is_none());
+        assert!(response.text.is_none());
+        assert!(response.parallel_tool_calls.is_none());
+    }
+
+    #[test]
+    fn parses_code_interpreter_calls() {
+        use crate::types::{
+            Annotation, CodeInterpreterContainer, CodeInterpreterOutput, OutputContent, Tool,
+        };
+
+        let response: Response =
+            serde_json::from_str(include_str!("../../tests/fixtures/code_interpreter.json"))
+                .unwrap();
+
+        let OutputItem::CodeInterpreterCall(call) = &response.output[0] else {
+            panic!("expected a code interpreter call");
+        };
+        assert!(call.code.as_deref().unwrap().contains("plt.savefig"));
+        assert!(matches!(
+            call.outputs.as_deref(),
+            Some([
+                CodeInterpreterOutput::Logs { .. },
+                CodeInterpreterOutput::Image { .. }
+            ])
+        ));
+
+        let mut echoed = call.clone();
+        echoed.outputs = None;
+        assert!(
+            serde_json::to_value(&echoed)
+                .unwrap()
+                .get("outputs")
+                .is_none()
+        );
+
+        let OutputItem::Message(message) = &response.output[1] else {
+            panic!("expected a message");
+        };
+        let OutputContent::Text { annotations, .. } = &message.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(
+            matches!(&annotations[0], Annotation::ContainerFileCitation { filename, .. } if filename == "line.png")
+        );
+
+        assert!(matches!(
+            &response.tools[0],
+            Tool::CodeInterpreter {
+                container: CodeInterpreterContainer::Auto(_)
+            }
+        ));
+    }
 
-impl From<ResponseResult> for Result<Response, Error> {
-    fn from(val: ResponseResult) -> Self {
-        match val {
-            ResponseResult::Err { error } => Err(error),
-            ResponseResult::Ok(response) => Ok(*response),
+    #[test]
+    fn output_as_input_keeps_encrypted_reasoning() {
+        use crate::types::{FunctionCallOutput, Input, InputListItem, Request};
+
+        let response: Response = serde_json::from_str(include_str!(
+            "../../tests/fixtures/o4_mini_encrypted_reasoning.json"
+        ))
+        .unwrap();
+
+        let mut input = response.output_as_input();
+        input.push(InputListItem::Item(InputItem::FunctionCallOutput(
+            FunctionCallOutput {
+                id: None,
+                status: None,
+                call_id: "call_pWq3dKdV1sGx7u9ZtNfYb2eR".to_string(),
+                output: "{\"temperature\":18}".to_string(),
+                extra: serde_json::Map::new(),
+            },
+        )));
+        let request = Request {
+            input: Input::List(input),
+            store: Some(false),
+            ..Default::default()
+        };
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            body["input"][0],
+            serde_json::json!({
+                "type": "reasoning",
+                "id": "rs_68a4c1f1a2b88193b4e5c6d7e8f90a1b0b5c7d9e1f3a5b7c",
+                "summary": [],
+                "encrypted_content": "gAAAAABopMHy3vQx9kNRzJ1u8bR0y2VwYm5oZ0xKc1RrQmVhT2RrY0F5cXh0M2pQ",
+            })
+        );
+        assert_eq!(body["input"][1]["type"], "function_call");
+        assert_eq!(body["input"][2]["type"], "function_call_output");
+    }
+
+    #[test]
+    fn parses_function_call_arguments() {
+        #[derive(Debug, serde::Deserialize)]
+        struct GetWeather {
+            location: String,
         }
+
+        let response: Response =
+            serde_json::from_str(include_str!("../../tests/fixtures/function_call.json")).unwrap();
+        let Some(OutputItem::FunctionCall(call)) = response.output.get(1) else {
+            panic!("expected a function call");
+        };
+
+        let args = call.parse_arguments::<GetWeather>().unwrap();
+        assert_eq!(args.location, "Paris, France");
+
+        let error = call.parse_arguments::<Vec<u8>>().unwrap_err();
+        assert_eq!(error.name, "get_weather");
+        assert_eq!(error.call_id, call.call_id);
+        assert!(
+            error
+                .to_string()
+                .starts_with("Failed to parse the arguments of the `get_weather` function call:")
+        );
+    }
+
+    #[test]
+    fn parses_custom_tool_calls() {
+        use crate::types::{CustomToolCallOutput, InputItem};
+
+        let item: OutputItem = serde_json::from_value(serde_json::json!({
+            "type": "custom_tool_call",
+            "id": "ctc_6890e975e86c819c9338825b3e1994810694874912ae0ea6",
+            "call_id": "call_aGiFQkRWSWAIsMQ19fKqxUgb",
+            "name": "run_sql",
+            "input": "SELECT name FROM users;",
+            "status": "completed",
+        }))
+        .unwrap();
+        let OutputItem::CustomToolCall(call) = item else {
+            panic!("expected a custom tool call");
+        };
+        assert_eq!(call.input, "SELECT name FROM users;");
+
+        let output = InputItem::CustomToolCallOutput(CustomToolCallOutput {
+            id: None,
+            call_id: call.call_id,
+            output: "alice\nbob".to_string(),
+            extra: serde_json::Map::new(),
+        });
+        assert_eq!(
+            serde_json::to_value(output).unwrap(),
+            serde_json::json!({
+                "type": "custom_tool_call_output",
+                "call_id": "call_aGiFQkRWSWAIsMQ19fKqxUgb",
+                "output": "alice\nbob",
+            })
+        );
+    }
+
+    #[test]
+    fn answers_mcp_approval_requests() {
+        use crate::types::{McpApprovalPolicy, McpApprovalSetting, Tool};
+
+        let response: Response =
+            serde_json::from_str(include_str!("../../tests/fixtures/mcp_approval.json")).unwrap();
+
+        let OutputItem::McpListTools(list) = &response.output[0] else {
+            panic!("expected an MCP tool list");
+        };
+        assert_eq!(list.tools[0].name, "read_wiki_structure");
+        assert!(matches!(
+            &response.tools[0],
+            Tool::Mcp {
+                require_approval: Some(McpApprovalPolicy::All(McpApprovalSetting::Always)),
+                ..
+            }
+        ));
+
+        let OutputItem::McpApprovalRequest(request) = &response.output[1] else {
+            panic!("expected an MCP approval request");
+        };
+        assert_eq!(
+            serde_json::to_value(request.approve()).unwrap(),
+            serde_json::json!({
+                "type": "mcp_approval_response",
+                "approval_request_id": "mcpr_682d498e3bd4819196a0ce1664f8e77b04ad1e533afccbfa",
+                "approve": true,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(request.deny(Some("Not allowed".to_string()))).unwrap(),
+            serde_json::json!({
+                "type": "mcp_approval_response",
+                "approval_request_id": "mcpr_682d498e3bd4819196a0ce1664f8e77b04ad1e533afccbfa",
+                "approve": false,
+                "reason": "Not allowed",
+            })
+        );
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn parses_response_created_event() {
+        use crate::types::Event;
+
+        let event: Event = serde_json::from_str(include_str!(
+            "../../tests/fixtures/response_created_event.json"
+        ))
+        .unwrap();
+
+        let Event::ResponseCreated { response, .. } = event else {
+            panic!("expected a response.created event");
+        };
+        assert!(response.text.is_none());
+        assert!(response.reasoning.is_none());
+        assert!(response.temperature.is_none());
+        assert!(response.parallel_tool_calls.is_none());
+    }
+
+    #[cfg(feature = "extra-fields")]
+    fn assert_round_trips(fixture: &str) {
+        let json: serde_json::Value = serde_json::from_str(fixture).unwrap();
+        let response: Response = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&response).unwrap(), json);
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn keeps_unknown_fields() {
+        let response: Response =
+            serde_json::from_str(include_str!("../../tests/fixtures/computer_use.json")).unwrap();
+
+        assert_eq!(response.extra["object"], "response");
+        let OutputItem::Reasoning(reasoning) = &response.output[0] else {
+            panic!("expected a reasoning item");
+        };
+        assert_eq!(
+            reasoning.encrypted_content.as_deref(),
+            Some("gAAAAABn0Mq8a9XbU3f2xQ...")
+        );
+        assert!(!reasoning.extra.contains_key("encrypted_content"));
+        let OutputItem::ComputerToolCall(call) = &response.output[1] else {
+            panic!("expected a computer call");
+        };
+        assert_eq!(
+            call.extra["id"],
+            "cu_67cc4a1e2a4c8190a2b7e3f15d9c8e4b0b8f4ad9c6a1e1d2"
+        );
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn fixtures_round_trip() {
+        assert_round_trips(include_str!("../../tests/fixtures/computer_use.json"));
+        assert_round_trips(include_str!("../../tests/fixtures/function_call.json"));
+        assert_round_trips(include_str!("../../tests/fixtures/o3_reasoning.json"));
+        assert_round_trips(include_str!(
+            "../../tests/fixtures/o4_mini_encrypted_reasoning.json"
+        ));
     }
 }
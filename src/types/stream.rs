@@ -1,39 +1,58 @@
 use serde::{Deserialize, Serialize};
 
-use super::{Annotation, OutputContent, OutputItem, Response};
+use super::{Annotation, OutputContent, OutputItem, ReasoningSummary, Response};
 
+/// An event emitted while streaming a response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type")]
+#[serde(remote = "Self", tag = "type")]
 pub enum Event {
     /// An event that is emitted when a response is created.
     #[serde(rename = "response.created")]
     ResponseCreated {
         /// The response that was created.
         response: Response,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a response is queued and waiting to be processed.
+    #[serde(rename = "response.queued")]
+    ResponseQueued {
+        /// The full response object that is queued.
+        response: Response,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when the response is in progress.
     #[serde(rename = "response.in_progress")]
     ResponseInProgress {
         /// The response that is in progress.
         response: Response,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when the model response is complete.
     #[serde(rename = "response.completed")]
     ResponseCompleted {
         /// Properties of the completed response.
         response: Response,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// An event that is emitted when a response fails.
     #[serde(rename = "response.failed")]
     ResponseFailed {
         /// The response that failed.
         response: Response,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     }, // todo: probably we just care about error?
     /// An event that is emitted when a response finishes as incomplete.
     #[serde(rename = "response.incomplete")]
     ResponseIncomplete {
         /// The response that was incomplete.
         response: Response,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a new output item is added.
     #[serde(rename = "response.output_item.added")]
@@ -42,6 +61,8 @@ pub enum Event {
         item: OutputItem,
         /// The index of the output item that was added.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when an output item is marked done.
     #[serde(rename = "response.output_item.done")]
@@ -50,6 +71,8 @@ pub enum Event {
         item: OutputItem,
         /// The index of the output item that was marked done.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a new content part is added.
     #[serde(rename = "response.content_part.added")]
@@ -62,6 +85,8 @@ pub enum Event {
         output_index: u64,
         /// The content part that was added.
         part: OutputContent,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a content part is done.
     #[serde(rename = "response.content_part.done")]
@@ -74,6 +99,8 @@ pub enum Event {
         output_index: u64,
         /// The content part that is done.
         part: OutputContent,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when there is an additional text delta.
     #[serde(rename = "response.output_text.delta")]
@@ -86,6 +113,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the text delta was added to.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a text annotation is added.
     #[serde(rename = "response.output_text.annotation.added")]
@@ -100,6 +129,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the text annotation was added to.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when text content is finalized.
     #[serde(rename = "response.output_text.done")]
@@ -112,6 +143,22 @@ pub enum Event {
         output_index: u64,
         /// The text content that is finalized.
         text: String,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted with the log probabilities of the tokens in a text delta, when requested.
+    #[serde(rename = "response.output_text.logprobs")]
+    OutputTextLogprobs {
+        /// The index of the content part the log probabilities belong to.
+        content_index: u64,
+        /// The ID of the output item the log probabilities belong to.
+        item_id: String,
+        /// The log probabilities of the tokens in the delta.
+        logprobs: Vec<LogProb>,
+        /// The index of the output item the log probabilities belong to.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when there is a partial refusal text.
     #[serde(rename = "response.refusal.delta")]
@@ -124,6 +171,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the refusal text is added to.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when refusal text is finalized.
     #[serde(rename = "response.refusal.done")]
@@ -136,6 +185,120 @@ pub enum Event {
         output_index: u64,
         /// The refusal text that is finalized.
         refusal: String,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when there is a partial audio response.
+    #[serde(rename = "response.audio.delta")]
+    AudioDelta {
+        /// A chunk of Base64 encoded response audio bytes.
+        delta: String,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the audio response is complete.
+    #[serde(rename = "response.audio.done")]
+    AudioDone {
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when there is a partial transcript of audio.
+    #[serde(rename = "response.audio.transcript.delta")]
+    AudioTranscriptDelta {
+        /// The partial transcript of the audio response.
+        delta: String,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the full audio transcript is completed.
+    #[serde(rename = "response.audio.transcript.done")]
+    AudioTranscriptDone {
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a new reasoning summary part is added.
+    #[serde(rename = "response.reasoning_summary_part.added")]
+    ReasoningSummaryPartAdded {
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The summary part that was added.
+        part: ReasoningSummary,
+        /// The index of the summary part within the reasoning summary.
+        summary_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a reasoning summary part is completed.
+    #[serde(rename = "response.reasoning_summary_part.done")]
+    ReasoningSummaryPartDone {
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The completed summary part.
+        part: ReasoningSummary,
+        /// The index of the summary part within the reasoning summary.
+        summary_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a delta is added to a reasoning summary text.
+    #[serde(rename = "response.reasoning_summary_text.delta")]
+    ReasoningSummaryTextDelta {
+        /// The text delta that was added to the summary.
+        delta: String,
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The index of the summary part within the reasoning summary.
+        summary_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a reasoning summary text is completed.
+    #[serde(rename = "response.reasoning_summary_text.done")]
+    ReasoningSummaryTextDone {
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The index of the summary part within the reasoning summary.
+        summary_index: u64,
+        /// The full text of the completed reasoning summary.
+        text: String,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a delta is added to a reasoning text.
+    #[serde(rename = "response.reasoning_text.delta")]
+    ReasoningTextDelta {
+        /// The index of the reasoning content part.
+        content_index: u64,
+        /// The text delta that was added to the reasoning content.
+        delta: String,
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a reasoning text is completed.
+    #[serde(rename = "response.reasoning_text.done")]
+    ReasoningTextDone {
+        /// The index of the reasoning content part.
+        content_index: u64,
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The full text of the completed reasoning content.
+        text: String,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when there is a partial function-call arguments delta.
     #[serde(rename = "response.function_call_arguments.delta")]
@@ -146,6 +309,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the function-call arguments delta is added to.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when function-call arguments are finalized.
     #[serde(rename = "response.function_call_arguments.done")]
@@ -156,6 +321,32 @@ pub enum Event {
         item_id: String,
         /// The index of the output item.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when there is a delta (partial update) to the input of a custom tool call.
+    #[serde(rename = "response.custom_tool_call_input.delta")]
+    CustomToolCallInputDelta {
+        /// The incremental input data (delta) for the custom tool call.
+        delta: String,
+        /// Unique identifier for the API item associated with this event.
+        item_id: String,
+        /// The index of the output this delta applies to.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the input of a custom tool call is complete.
+    #[serde(rename = "response.custom_tool_call_input.done")]
+    CustomToolCallInputDone {
+        /// The complete input data for the custom tool call.
+        input: String,
+        /// Unique identifier for the API item associated with this event.
+        item_id: String,
+        /// The index of the output this event applies to.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a file search call is initiated.
     #[serde(rename = "response.file_search_call.in_progress")]
@@ -164,6 +355,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the file search call is initiated.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a file search is currently searching.
     #[serde(rename = "response.file_search_call.searching")]
@@ -172,6 +365,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the file search call is searching.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a file search call is completed (results found).
     #[serde(rename = "response.file_search_call.completed")]
@@ -180,6 +375,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the file search call completed at.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a web search call is initiated.
     #[serde(rename = "response.web_search_call.in_progress")]
@@ -188,6 +385,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the web search call is associated with.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a web search call is executing.
     #[serde(rename = "response.web_search_call.searching")]
@@ -196,6 +395,8 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the web search call is associated with.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when a web search call is completed.
     #[serde(rename = "response.web_search_call.completed")]
@@ -204,6 +405,196 @@ pub enum Event {
         item_id: String,
         /// The index of the output item that the web search call is associated with.
         output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when an image generation tool call is in progress.
+    #[serde(rename = "response.image_generation_call.in_progress")]
+    ImageGenerationCallInProgress {
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when an image generation tool call is actively generating an image.
+    #[serde(rename = "response.image_generation_call.generating")]
+    ImageGenerationCallGenerating {
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a partial image is available during image generation streaming.
+    #[serde(rename = "response.image_generation_call.partial_image")]
+    ImageGenerationCallPartialImage {
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// Base64-encoded partial image data, suitable for rendering as an image.
+        partial_image_b64: String,
+        /// 0-based index for the partial image. Requested with the `partial_images` option of the image generation tool.
+        partial_image_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when an image generation tool call has completed and the final image is available.
+    #[serde(rename = "response.image_generation_call.completed")]
+    ImageGenerationCallCompleted {
+        /// The ID of the item this event applies to.
+        item_id: String,
+        /// The index of the output item this event applies to.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a code interpreter call is in progress.
+    #[serde(rename = "response.code_interpreter_call.in_progress")]
+    CodeInterpreterCallInProgress {
+        /// The ID of the code interpreter tool call item.
+        item_id: String,
+        /// The index of the output item in the response for which the code interpreter call is in progress.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the code interpreter is actively interpreting the code snippet.
+    #[serde(rename = "response.code_interpreter_call.interpreting")]
+    CodeInterpreterCallInterpreting {
+        /// The ID of the code interpreter tool call item.
+        item_id: String,
+        /// The index of the output item in the response for which the code interpreter is interpreting code.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the code interpreter call is completed.
+    #[serde(rename = "response.code_interpreter_call.completed")]
+    CodeInterpreterCallCompleted {
+        /// The ID of the code interpreter tool call item.
+        item_id: String,
+        /// The index of the output item in the response for which the code interpreter call is completed.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when a partial code snippet is streamed by the code interpreter.
+    #[serde(rename = "response.code_interpreter_call_code.delta")]
+    CodeInterpreterCallCodeDelta {
+        /// The partial code snippet being streamed by the code interpreter.
+        delta: String,
+        /// The ID of the code interpreter tool call item.
+        item_id: String,
+        /// The index of the output item in the response for which the code is being streamed.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the code snippet is finalized by the code interpreter.
+    #[serde(rename = "response.code_interpreter_call_code.done")]
+    CodeInterpreterCallCodeDone {
+        /// The final code snippet output by the code interpreter.
+        code: String,
+        /// The ID of the code interpreter tool call item.
+        item_id: String,
+        /// The index of the output item in the response for which the code is finalized.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when there is a delta (partial update) to the arguments of an MCP tool call.
+    #[serde(rename = "response.mcp_call_arguments.delta")]
+    McpCallArgumentsDelta {
+        /// The partial update to the arguments for the MCP tool call.
+        delta: String,
+        /// The ID of the MCP tool call item being processed.
+        item_id: String,
+        /// The index of the output item in the response.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the arguments for an MCP tool call are finalized.
+    #[serde(rename = "response.mcp_call_arguments.done")]
+    McpCallArgumentsDone {
+        /// A JSON string containing the finalized arguments for the MCP tool call.
+        arguments: String,
+        /// The ID of the MCP tool call item being processed.
+        item_id: String,
+        /// The index of the output item in the response.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when an MCP tool call is in progress.
+    #[serde(rename = "response.mcp_call.in_progress")]
+    McpCallInProgress {
+        /// The ID of the MCP tool call item being processed.
+        item_id: String,
+        /// The index of the output item in the response.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when an MCP tool call has completed successfully.
+    #[serde(rename = "response.mcp_call.completed")]
+    McpCallCompleted {
+        /// The ID of the MCP tool call item that completed.
+        item_id: String,
+        /// The index of the output item in the response.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when an MCP tool call has failed.
+    #[serde(rename = "response.mcp_call.failed")]
+    McpCallFailed {
+        /// The ID of the MCP tool call item that failed.
+        item_id: String,
+        /// The index of the output item in the response.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the system is in the process of retrieving the list of available MCP tools.
+    #[serde(rename = "response.mcp_list_tools.in_progress")]
+    McpListToolsInProgress {
+        /// The ID of the MCP tool call item that is being processed.
+        item_id: String,
+        /// The index of the output item in the response.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the list of available MCP tools has been successfully retrieved.
+    #[serde(rename = "response.mcp_list_tools.completed")]
+    McpListToolsCompleted {
+        /// The ID of the MCP tool call item that produced this output.
+        item_id: String,
+        /// The index of the output item in the response.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Emitted when the attempt to list available MCP tools has failed.
+    #[serde(rename = "response.mcp_list_tools.failed")]
+    McpListToolsFailed {
+        /// The ID of the MCP tool call item that failed.
+        item_id: String,
+        /// The index of the output item in the response.
+        output_index: u64,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
+    },
+    /// Sent periodically while the model is working, to keep the connection open. Carries no data.
+    #[serde(rename = "keepalive")]
+    Keepalive {
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
     /// Emitted when an error occurs.
     #[serde(rename = "error")]
@@ -214,5 +605,393 @@ pub enum Event {
         message: String,
         /// The error parameter.
         param: Option<String>,
+        /// The sequence number of this event.
+        sequence_number: Option<u64>,
     },
+    /// An event of a type this crate doesn't know about yet.
+    ///
+    /// The original JSON is kept in `raw`, and serialized back unchanged.
+    #[serde(skip)]
+    Unknown {
+        /// The `type` of the event.
+        r#type: String,
+        /// The event as returned by the API.
+        raw: serde_json::Value,
+    },
+}
+
+tagged_with_unknown!(Event);
+
+impl Event {
+    /// The sequence number of this event, used to resume a stream with [`Client::resume_stream`](crate::Client::resume_stream).
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn sequence_number(&self) -> Option<u64> {
+        match self {
+            Self::ResponseCreated {
+                sequence_number, ..
+            }
+            | Self::ResponseInProgress {
+                sequence_number, ..
+            }
+            | Self::ResponseCompleted {
+                sequence_number, ..
+            }
+            | Self::ResponseFailed {
+                sequence_number, ..
+            }
+            | Self::ResponseIncomplete {
+                sequence_number, ..
+            }
+            | Self::OutputItemAdded {
+                sequence_number, ..
+            }
+            | Self::OutputItemDone {
+                sequence_number, ..
+            }
+            | Self::ContentPartAdded {
+                sequence_number, ..
+            }
+            | Self::ContentPartDone {
+                sequence_number, ..
+            }
+            | Self::OutputTextDelta {
+                sequence_number, ..
+            }
+            | Self::OutputTextAnnotationAdded {
+                sequence_number, ..
+            }
+            | Self::OutputTextDone {
+                sequence_number, ..
+            }
+            | Self::RefusalDelta {
+                sequence_number, ..
+            }
+            | Self::RefusalDone {
+                sequence_number, ..
+            }
+            | Self::FunctionCallArgumentsDelta {
+                sequence_number, ..
+            }
+            | Self::FunctionCallArgumentsDone {
+                sequence_number, ..
+            }
+            | Self::CustomToolCallInputDelta {
+                sequence_number, ..
+            }
+            | Self::CustomToolCallInputDone {
+                sequence_number, ..
+            }
+            | Self::FileSearchCallInitiated {
+                sequence_number, ..
+            }
+            | Self::FileSearchCallSearching {
+                sequence_number, ..
+            }
+            | Self::FileSearchCallCompleted {
+                sequence_number, ..
+            }
+            | Self::WebSearchCallInitiated {
+                sequence_number, ..
+            }
+            | Self::WebSearchCallSearching {
+                sequence_number, ..
+            }
+            | Self::WebSearchCallCompleted {
+                sequence_number, ..
+            }
+            | Self::CodeInterpreterCallInProgress {
+                sequence_number, ..
+            }
+            | Self::CodeInterpreterCallInterpreting {
+                sequence_number, ..
+            }
+            | Self::CodeInterpreterCallCompleted {
+                sequence_number, ..
+            }
+            | Self::CodeInterpreterCallCodeDelta {
+                sequence_number, ..
+            }
+            | Self::CodeInterpreterCallCodeDone {
+                sequence_number, ..
+            }
+            | Self::McpCallArgumentsDelta {
+                sequence_number, ..
+            }
+            | Self::McpCallArgumentsDone {
+                sequence_number, ..
+            }
+            | Self::McpCallInProgress {
+                sequence_number, ..
+            }
+            | Self::McpCallCompleted {
+                sequence_number, ..
+            }
+            | Self::McpCallFailed {
+                sequence_number, ..
+            }
+            | Self::McpListToolsInProgress {
+                sequence_number, ..
+            }
+            | Self::McpListToolsCompleted {
+                sequence_number, ..
+            }
+            | Self::McpListToolsFailed {
+                sequence_number, ..
+            }
+            | Self::ResponseQueued {
+                sequence_number, ..
+            }
+            | Self::OutputTextLogprobs {
+                sequence_number, ..
+            }
+            | Self::AudioDelta {
+                sequence_number, ..
+            }
+            | Self::AudioDone {
+                sequence_number, ..
+            }
+            | Self::AudioTranscriptDelta {
+                sequence_number, ..
+            }
+            | Self::AudioTranscriptDone {
+                sequence_number, ..
+            }
+            | Self::ReasoningSummaryPartAdded {
+                sequence_number, ..
+            }
+            | Self::ReasoningSummaryPartDone {
+                sequence_number, ..
+            }
+            | Self::ReasoningSummaryTextDelta {
+                sequence_number, ..
+            }
+            | Self::ReasoningSummaryTextDone {
+                sequence_number, ..
+            }
+            | Self::ReasoningTextDelta {
+                sequence_number, ..
+            }
+            | Self::ReasoningTextDone {
+                sequence_number, ..
+            }
+            | Self::ImageGenerationCallInProgress {
+                sequence_number, ..
+            }
+            | Self::ImageGenerationCallGenerating {
+                sequence_number, ..
+            }
+            | Self::ImageGenerationCallPartialImage {
+                sequence_number, ..
+            }
+            | Self::ImageGenerationCallCompleted {
+                sequence_number, ..
+            }
+            | Self::Keepalive {
+                sequence_number, ..
+            }
+            | Self::Error {
+                sequence_number, ..
+            } => *sequence_number,
+            Self::Unknown { raw, .. } => raw
+                .get("sequence_number")
+                .and_then(serde_json::Value::as_u64),
+        }
+    }
+
+    /// The response carried by this event, for events that include the full response.
+    #[must_use]
+    pub const fn response(&self) -> Option<&Response> {
+        match self {
+            Self::ResponseCreated { response, .. }
+            | Self::ResponseQueued { response, .. }
+            | Self::ResponseInProgress { response, .. }
+            | Self::ResponseCompleted { response, .. }
+            | Self::ResponseFailed { response, .. }
+            | Self::ResponseIncomplete { response, .. } => Some(response),
+            _ => None,
+        }
+    }
+
+    /// Whether this is the last event of a stream.
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::ResponseCompleted { .. }
+                | Self::ResponseFailed { .. }
+                | Self::ResponseIncomplete { .. }
+                | Self::Error { .. }
+        )
+    }
+}
+
+/// The log probability of a token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogProb {
+    /// The token.
+    pub token: String,
+    /// The log probability of the token.
+    pub logprob: f64,
+    /// The most likely tokens at this position, with their log probabilities.
+    #[serde(default)]
+    pub top_logprobs: Vec<TopLogProb>,
+}
+
+/// One of the most likely tokens at a position, with its log probability.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopLogProb {
+    /// The token.
+    pub token: String,
+    /// The log probability of the token.
+    pub logprob: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ResponseStatus;
+
+    /// Hand-written streams modelled on the event shapes the API sends, one per event family.
+    const TRANSCRIPTS: [(&str, &str); 10] = [
+        (
+            "text",
+            include_str!("../../tests/fixtures/streams/text.sse"),
+        ),
+        (
+            "refusal",
+            include_str!("../../tests/fixtures/streams/refusal.sse"),
+        ),
+        (
+            "reasoning",
+            include_str!("../../tests/fixtures/streams/reasoning.sse"),
+        ),
+        (
+            "image generation",
+            include_str!("../../tests/fixtures/streams/image_generation.sse"),
+        ),
+        (
+            "audio",
+            include_str!("../../tests/fixtures/streams/audio.sse"),
+        ),
+        (
+            "tools",
+            include_str!("../../tests/fixtures/streams/tools.sse"),
+        ),
+        (
+            "code interpreter",
+            include_str!("../../tests/fixtures/streams/code_interpreter.sse"),
+        ),
+        ("mcp", include_str!("../../tests/fixtures/streams/mcp.sse")),
+        (
+            "failed",
+            include_str!("../../tests/fixtures/streams/failed.sse"),
+        ),
+        (
+            "error",
+            include_str!("../../tests/fixtures/streams/error.sse"),
+        ),
+    ];
+
+    fn parse_transcript(family: &str, transcript: &str) -> Vec<Event> {
+        transcript
+            .lines()
+            .filter_map(|line| line.strip_prefix("data: "))
+            .map(|data| {
+                serde_json::from_str(data)
+                    .unwrap_or_else(|error| panic!("failed to parse {family} event: {error}"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_every_event_family() {
+        for (family, transcript) in TRANSCRIPTS {
+            let events = parse_transcript(family, transcript);
+
+            for (index, event) in (0..).zip(&events) {
+                assert!(
+                    !matches!(event, Event::Unknown { .. }),
+                    "{family}: unrecognized event {event:?}"
+                );
+                assert_eq!(event.sequence_number(), Some(index), "{family}");
+            }
+            assert!(events.last().is_some_and(Event::is_terminal), "{family}");
+        }
+    }
+
+    #[test]
+    fn parses_event_payloads() {
+        let events = parse_transcript("text", TRANSCRIPTS[0].1);
+        assert!(matches!(
+            &events[5],
+            Event::OutputTextLogprobs { logprobs, .. }
+                if logprobs[0].token == "Paris" && logprobs[0].top_logprobs.len() == 2
+        ));
+        assert!(matches!(events[7], Event::Keepalive { .. }));
+
+        let events = parse_transcript("reasoning", TRANSCRIPTS[2].1);
+        assert!(matches!(
+            &events[6],
+            Event::ReasoningSummaryTextDone { summary_index: 0, text, .. }
+                if text.starts_with("**Comparing decimals**")
+        ));
+
+        let events = parse_transcript("image generation", TRANSCRIPTS[3].1);
+        assert_eq!(
+            events[0].response().map(|response| response.status),
+            Some(ResponseStatus::Queued)
+        );
+        assert!(matches!(
+            &events[6],
+            Event::ImageGenerationCallPartialImage { partial_image_index: 0, partial_image_b64, .. }
+                if partial_image_b64.starts_with("iVBORw0KGgo")
+        ));
+    }
+
+    #[test]
+    fn parses_code_interpreter_events() {
+        use serde_json::json;
+
+        let events = [
+            json!({ "type": "response.code_interpreter_call.in_progress", "sequence_number": 1, "item_id": "ci_123", "output_index": 0 }),
+            json!({ "type": "response.code_interpreter_call_code.delta", "sequence_number": 2, "delta": "print(", "item_id": "ci_123", "output_index": 0 }),
+            json!({ "type": "response.code_interpreter_call_code.done", "sequence_number": 3, "code": "print(1)", "item_id": "ci_123", "output_index": 0 }),
+            json!({ "type": "response.code_interpreter_call.interpreting", "sequence_number": 4, "item_id": "ci_123", "output_index": 0 }),
+            json!({ "type": "response.code_interpreter_call.completed", "sequence_number": 5, "item_id": "ci_123", "output_index": 0 }),
+        ]
+        .map(|event| serde_json::from_value::<Event>(event).unwrap());
+
+        assert!(matches!(
+            &events,
+            [
+                Event::CodeInterpreterCallInProgress { .. },
+                Event::CodeInterpreterCallCodeDelta { delta, .. },
+                Event::CodeInterpreterCallCodeDone { code, .. },
+                Event::CodeInterpreterCallInterpreting { .. },
+                Event::CodeInterpreterCallCompleted { item_id, .. },
+            ] if delta == "print(" && code == "print(1)" && item_id == "ci_123"
+        ));
+        assert_eq!(events[4].sequence_number(), Some(5));
+    }
+
+    #[test]
+    fn parses_custom_tool_call_input_events() {
+        use serde_json::json;
+
+        let events = [
+            json!({ "type": "response.custom_tool_call_input.delta", "sequence_number": 1, "delta": "SELECT ", "item_id": "ctc_123", "output_index": 0 }),
+            json!({ "type": "response.custom_tool_call_input.done", "sequence_number": 2, "input": "SELECT 1;", "item_id": "ctc_123", "output_index": 0 }),
+        ]
+        .map(|event| serde_json::from_value::<Event>(event).unwrap());
+
+        assert!(matches!(
+            &events,
+            [
+                Event::CustomToolCallInputDelta { delta, .. },
+                Event::CustomToolCallInputDone { input, .. },
+            ] if delta == "SELECT " && input == "SELECT 1;"
+        ));
+        assert_eq!(events[1].sequence_number(), Some(2));
+    }
 }
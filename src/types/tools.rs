@@ -7,7 +7,7 @@ use std::collections::HashMap;
 /// - **Built-in tools**: Tools that are provided by OpenAI that extend the model's capabilities, like [web search](https://platform.openai.com/docs/guides/tools-web-search) or [file search](https://platform.openai.com/docs/guides/tools-file-search). Learn more about [built-in tools](https://platform.openai.com/docs/guides/tools).
 /// - **Function calls (custom tools)**: Functions that are defined by you, enabling the model to call your own code. Learn more about [function calling](https://platform.openai.com/docs/guides/function-calling).
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum Tool {
     /// Defines a function in your own code the model can choose to call. Learn more about [function calling](https://platform.openai.com/docs/guides/function-calling).
     Function {
@@ -18,18 +18,33 @@ pub enum Tool {
         /// Whether to enforce strict parameter validation.
         strict: bool,
         /// A description of the function. Used by the model to determine whether or not to call the function.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
+    /// A custom tool that processes input using a specified format, instead of a JSON schema. Learn more about [custom tools](https://platform.openai.com/docs/guides/function-calling#custom-tools).
+    Custom {
+        /// The name of the custom tool, used to identify it in tool calls.
+        name: String,
+        /// A description of the custom tool, used to provide more context to the model.
+        #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        /// The input format for the custom tool. Defaults to unconstrained text.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        format: Option<CustomToolFormat>,
     },
     /// A tool that searches for relevant content from uploaded files. Learn more about the [file search tool](https://platform.openai.com/docs/guides/tools-file-search).
     FileSearch {
         /// The IDs of the vector stores to search.
         vector_store_ids: Vec<String>,
         /// A filter to apply based on file attributes.
-        filters: FileSearchFilters,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filters: Option<FileSearchFilters>,
         /// The maximum number of results to return. This number should be between 1 and 50 inclusive.
-        max_num_results: u8,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_num_results: Option<u8>,
         /// Ranking options for search.
-        ranking_options: RankingOptions,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ranking_options: Option<RankingOptions>,
     },
     #[serde(rename = "computer_use_preview")]
     ComputerUse {
@@ -45,6 +60,7 @@ pub enum Tool {
         /// High level guidance for the amount of context window space to use for the search.
         search_context_size: SearchContextSize,
         /// Approximate location parameters for the search.
+        #[serde(skip_serializing_if = "Option::is_none")]
         user_location: Option<UserLocation>,
     },
     /// A tool that allows the model to generate, edit, or in-paint an image.
@@ -77,6 +93,173 @@ pub enum Tool {
         #[serde(skip_serializing_if = "Option::is_none")]
         size: Option<ImageSize>,
     },
+    /// Give the model access to additional tools via remote Model Context Protocol (MCP) servers. Learn more about [MCP](https://platform.openai.com/docs/guides/tools-remote-mcp).
+    Mcp {
+        /// A label for this MCP server, used to identify it in tool calls.
+        server_label: String,
+        /// The URL for the MCP server.
+        server_url: String,
+        /// Optional HTTP headers to send to the MCP server. Use for authentication or other purposes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        headers: Option<HashMap<String, String>>,
+        /// The tools the model may call on this server. Defaults to every tool the server exposes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        allowed_tools: Option<McpAllowedTools>,
+        /// Which of the server's tools require approval before the model calls them. Defaults to always requiring approval.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        require_approval: Option<McpApprovalPolicy>,
+        /// A description of the MCP server, used to provide more context to the model.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        server_description: Option<String>,
+    },
+    /// A tool that allows the model to execute shell commands in a local environment. Learn more about the [local shell tool](https://platform.openai.com/docs/guides/tools-local-shell).
+    ///
+    /// Run the commands the model requests with a [`ShellExecutor`](crate::ShellExecutor).
+    LocalShell,
+    /// A tool that runs Python code to help generate a response to a prompt. Learn more about the [code interpreter tool](https://platform.openai.com/docs/guides/tools-code-interpreter).
+    CodeInterpreter {
+        /// The container to run the code in. Can be the ID of an existing container, or a new container created automatically.
+        container: CodeInterpreterContainer,
+    },
+    /// A tool of a type this crate doesn't know about yet.
+    ///
+    /// The original JSON is kept in `raw`, and serialized back unchanged.
+    #[serde(skip)]
+    Unknown {
+        /// The `type` of the tool.
+        r#type: String,
+        /// The tool as returned by the API.
+        raw: serde_json::Value,
+    },
+}
+
+tagged_with_unknown!(Tool);
+
+impl Tool {
+    /// A function tool whose parameters are described by the JSON schema of `Args`, with strict mode enabled.
+    ///
+    /// The schema is adjusted for [strict mode](https://platform.openai.com/docs/guides/function-calling#strict-mode):
+    /// objects don't allow additional properties and every property is required, with `Option` fields accepting `null` instead.
+    /// Parse the arguments of the resulting calls with [`FunctionCall::parse_arguments`](crate::types::FunctionCall::parse_arguments).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use openai_responses::types::Tool;
+    ///
+    /// #[derive(serde::Deserialize, schemars::JsonSchema)]
+    /// struct GetWeather {
+    ///     /// City and country, e.g. Paris, France.
+    ///     location: String,
+    ///     unit: Option<String>,
+    /// }
+    ///
+    /// let tool = Tool::function::<GetWeather>("get_weather", "Get the current weather for a location.");
+    /// ```
+    #[cfg(feature = "schemars")]
+    #[must_use]
+    pub fn function<Args: schemars::JsonSchema>(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self::Function {
+            name: name.into(),
+            strict: true,
+            description: Some(description.into()),
+            parameters: crate::schema::strict_schema_for::<Args>(),
+        }
+    }
+}
+
+/// The input format of a custom tool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CustomToolFormat {
+    /// Unconstrained free-form text.
+    Text,
+    /// Text constrained by a grammar.
+    Grammar {
+        /// The syntax of the grammar definition.
+        syntax: GrammarSyntax,
+        /// The grammar definition.
+        definition: String,
+    },
+}
+
+/// The syntax of a custom tool grammar.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GrammarSyntax {
+    /// A [Lark](https://lark-parser.readthedocs.io/) grammar.
+    Lark,
+    /// A regular expression.
+    Regex,
+}
+
+/// The container the code interpreter tool runs code in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CodeInterpreterContainer {
+    /// The ID of an existing container, e.g. `cntr_123`.
+    Id(String),
+    /// Create a new container automatically, or reuse an active one from a previous code interpreter call in the context.
+    Auto(AutoContainer),
+}
+
+impl Default for CodeInterpreterContainer {
+    fn default() -> Self {
+        Self::Auto(AutoContainer::default())
+    }
+}
+
+/// Configuration for a code interpreter container created automatically.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "auto")]
+pub struct AutoContainer {
+    /// The IDs of uploaded files to make available to your code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_ids: Option<Vec<String>>,
+}
+
+/// The tools the model may call on an MCP server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum McpAllowedTools {
+    /// A list of allowed tool names.
+    Names(Vec<String>),
+    /// A filter object to specify which tools are allowed.
+    Filter(McpToolFilter),
+}
+
+/// Which tools on an MCP server require approval before the model calls them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum McpApprovalPolicy {
+    /// Apply the same approval setting to every tool.
+    All(McpApprovalSetting),
+    /// Apply different approval settings to specific tools.
+    Filter {
+        /// The tools that always require approval.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        always: Option<McpToolFilter>,
+        /// The tools that never require approval.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        never: Option<McpToolFilter>,
+    },
+}
+
+/// Whether calls to MCP tools require approval.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum McpApprovalSetting {
+    Always,
+    Never,
+}
+
+/// A set of MCP tools, selected by name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct McpToolFilter {
+    /// The names of the tools.
+    pub tool_names: Vec<String>,
 }
 
 /// Approximate location parameters for the search.
@@ -85,12 +268,16 @@ pub struct UserLocation {
     /// The type of location approximation
     pub r#type: UserLocationType,
     /// Free text input for the city of the user, e.g. `San Francisco`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub city: Option<String>,
     /// The two-letter [ISO country code](https://en.wikipedia.org/wiki/ISO_3166-1) of the user, e.g. `US`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
     /// Free text input for the region of the user, e.g. `California`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
     /// The [IANA timezone](https://timeapi.io/documentation/iana-timezones) of the user, e.g. `America/Los_Angeles`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timezone: Option<String>,
 }
 
@@ -280,8 +467,12 @@ pub enum ToolChoice {
     ComputerUsePreview,
     /// Generate, edit, or in-paint an image.
     ImageGeneration,
+    /// Run Python code to help generate a response.
+    CodeInterpreter,
     /// Enable the model to call custom code that you define, giving it access to additional data and capabilities.
     Function(String),
+    /// Force the model to call the custom tool with the given name.
+    Custom(String),
 }
 
 impl<'de> Deserialize<'de> for ToolChoice {
@@ -332,12 +523,19 @@ impl<'de> Deserialize<'de> for ToolChoice {
                     "web_search_preview" => Ok(ToolChoice::WebSearchPreview),
                     "computer_use_preview" => Ok(ToolChoice::ComputerUsePreview),
                     "image_generation" => Ok(ToolChoice::ImageGeneration),
+                    "code_interpreter" => Ok(ToolChoice::CodeInterpreter),
                     "function" => {
                         let Some(name) = record.get("name") else {
                             return Err(serde::de::Error::missing_field("name"));
                         };
                         Ok(ToolChoice::Function(name.clone()))
                     }
+                    "custom" => {
+                        let Some(name) = record.get("name") else {
+                            return Err(serde::de::Error::missing_field("name"));
+                        };
+                        Ok(ToolChoice::Custom(name.clone()))
+                    }
                     _ => Err(serde::de::Error::unknown_variant(
                         r#type.as_str(),
                         &[
@@ -345,7 +543,9 @@ impl<'de> Deserialize<'de> for ToolChoice {
                             "web_search_preview",
                             "computer_use_preview",
                             "image_generation",
+                            "code_interpreter",
                             "function",
+                            "custom",
                         ],
                     )),
                 }
@@ -385,12 +585,23 @@ impl Serialize for ToolChoice {
                 fn_struct.serialize_field("type", "image_generation")?;
                 fn_struct.end()
             }
+            Self::CodeInterpreter => {
+                let mut fn_struct = serializer.serialize_struct("Function", 1)?;
+                fn_struct.serialize_field("type", "code_interpreter")?;
+                fn_struct.end()
+            }
             Self::Function(name) => {
                 let mut fn_struct = serializer.serialize_struct("Function", 2)?;
                 fn_struct.serialize_field("name", name)?;
                 fn_struct.serialize_field("type", "function")?;
                 fn_struct.end()
             }
+            Self::Custom(name) => {
+                let mut fn_struct = serializer.serialize_struct("Function", 2)?;
+                fn_struct.serialize_field("name", name)?;
+                fn_struct.serialize_field("type", "custom")?;
+                fn_struct.end()
+            }
         }
     }
 }